@@ -42,6 +42,17 @@ pub enum InlineAttr {
     Never,
 }
 
+/// The `#[mir_inline]` attribute, which overrides the cost the MIR inliner computes for
+/// a function.
+#[derive(Copy, Clone, PartialEq, Encodable, Decodable, Debug, HashStable_Generic)]
+pub enum MirInlineAttr {
+    None,
+    /// `#[mir_inline(always)]`: inline regardless of the cost.
+    Always,
+    /// `#[mir_inline(cost = N)]`: use `N` as the cost instead of the computed one.
+    Cost(usize),
+}
+
 #[derive(Clone, Encodable, Decodable, Debug, PartialEq, Eq, HashStable_Generic)]
 pub enum InstructionSetAttr {
     ArmA32,
@@ -1199,3 +1210,47 @@ pub fn parse_confusables(attr: &Attribute) -> Option<Vec<Symbol>> {
 
     return Some(candidates);
 }
+
+/// The content of a `#[rustc_unsupported_on]` or `#[rustc_expensive_on]` attribute, which marks
+/// an API as unsupported or as very expensive when compiling for the given `target_os`.
+#[derive(Copy, Clone, Debug, Encodable, Decodable, HashStable_Generic)]
+pub struct TargetRestriction {
+    pub target_os: Symbol,
+    pub reason: Option<Symbol>,
+}
+
+/// Read the content of a `rustc_unsupported_on` or `rustc_expensive_on` attribute, of the form
+/// `#[rustc_unsupported_on(target_os = "...", reason = "...")]` where `reason` is optional.
+pub fn parse_target_restriction(attr: &Attribute) -> Option<TargetRestriction> {
+    let mut target_os = None;
+    let mut reason = None;
+
+    for meta in attr.meta_item_list()? {
+        let meta = meta.meta_item()?;
+        let value = meta.value_str()?;
+        match meta.name_or_empty() {
+            sym::target_os if target_os.is_none() => target_os = Some(value),
+            sym::reason if reason.is_none() => reason = Some(value),
+            _ => return None,
+        }
+    }
+
+    Some(TargetRestriction { target_os: target_os?, reason })
+}
+
+/// Read the content of a `#[mir_inline]` attribute, which is either `#[mir_inline(always)]` or
+/// `#[mir_inline(cost = N)]` with an unsuffixed integer `N`.
+pub fn parse_mir_inline(attr: &Attribute) -> Option<MirInlineAttr> {
+    let [meta] = &attr.meta_item_list()?[..] else { return None };
+    let meta = meta.meta_item()?;
+    match meta.name_or_empty() {
+        sym::always if meta.is_word() => Some(MirInlineAttr::Always),
+        sym::cost => match meta.name_value_literal()?.kind {
+            LitKind::Int(cost, ast::LitIntType::Unsuffixed) => {
+                Some(MirInlineAttr::Cost(cost.try_into().ok()?))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -17,6 +17,8 @@ macro_rules! intrinsic_args {
 mod llvm_x86;
 mod simd;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use cranelift_codegen::ir::AtomicRmwOp;
 use rustc_middle::ty;
 use rustc_middle::ty::layout::{HasParamEnv, ValidityRequirement};
@@ -1245,6 +1247,23 @@ fn codegen_regular_intrinsic_call<'tcx>(
             ret.write_cvalue(fx, a);
         }
 
+        sym::value_barrier => {
+            intrinsic_args!(fx, args => (a); intrinsic);
+
+            // FIXME hide the value from the optimizer like the LLVM backend does
+            // Every use of `core::ct` ends up here, so only warn about the first one. A compiler
+            // process runs a single session, so a static is enough.
+            static WARNED: AtomicBool = AtomicBool::new(false);
+            if !WARNED.swap(true, Ordering::Relaxed) {
+                fx.tcx.sess.span_warn(
+                    source_info.span,
+                    "`value_barrier` is not supported by Cranelift, so the code using it is not \
+                    guaranteed to run in constant time",
+                );
+            }
+            ret.write_cvalue(fx, a);
+        }
+
         // FIXME implement variadics in cranelift
         sym::va_copy | sym::va_arg | sym::va_end => {
             fx.tcx.sess.span_fatal(
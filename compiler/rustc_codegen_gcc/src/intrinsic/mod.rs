@@ -336,6 +336,21 @@ fn codegen_intrinsic_call(&mut self, instance: Instance<'tcx>, fn_abi: &FnAbi<'t
                     return;
                 }
 
+                sym::value_barrier => {
+                    // Like `black_box`, this makes GCC forget the value by passing it
+                    // through an opaque asm block.
+                    args[0].val.store(self, result);
+
+                    let block = self.llbb();
+                    let extended_asm = block.add_extended_asm(None, "");
+                    extended_asm.add_input_operand(None, "r", result.llval);
+                    extended_asm.add_clobber("memory");
+                    extended_asm.set_volatile_flag(true);
+
+                    // We have copied the value to `result` already.
+                    return;
+                }
+
                 sym::ptr_mask => {
                     let usize_type = self.context.new_type::<usize>();
                     let void_ptr_type = self.context.new_type::<*const ()>();
@@ -3,6 +3,7 @@
 };
 use crate::errors::{
     DynamicLinkingWithLTO, LlvmError, LtoBitcodeFromRlib, LtoDisallowed, LtoDylib, LtoProcMacro,
+    UnreachableItems,
 };
 use crate::llvm::{self, build_string};
 use crate::{LlvmCodegenBackend, ModuleLlvm};
@@ -12,15 +13,16 @@
 use rustc_codegen_ssa::back::write::{CodegenContext, FatLtoInput, TargetMachineFactoryConfig};
 use rustc_codegen_ssa::traits::*;
 use rustc_codegen_ssa::{looks_like_rust_object_file, ModuleCodegen, ModuleKind};
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::memmap::Mmap;
 use rustc_errors::{FatalError, Handler};
-use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_hir::def_id::{CrateNum, LOCAL_CRATE};
 use rustc_middle::bug;
 use rustc_middle::dep_graph::WorkProduct;
 use rustc_middle::middle::exported_symbols::{SymbolExportInfo, SymbolExportLevel};
 use rustc_session::config::{self, CrateType, Lto};
 
+use std::collections::BTreeMap;
 use std::ffi::{CStr, CString};
 use std::fs::File;
 use std::io;
@@ -643,6 +645,52 @@ pub(crate) fn run_pass_manager(
     Ok(())
 }
 
+/// Implements `-Z print-unreachable-items`: reports the functions that each
+/// dependency exports and that are in the fat LTO module but never reached
+/// from the exported symbols of the final artifact, so global DCE will remove
+/// them. The report is a note per dependency, so that it goes through the
+/// session's diagnostics like everything else the LTO worker reports.
+pub(crate) fn print_unreachable_items(
+    cgcx: &CodegenContext<LlvmCodegenBackend>,
+    diag_handler: &Handler,
+    module: &ModuleCodegen<ModuleLlvm>,
+) {
+    let _timer = cgcx.prof.generic_activity("LLVM_lto_find_unreachable_items");
+    let unreachable = build_string(|s| unsafe {
+        llvm::LLVMRustWriteUnreachableFunctions(module.module_llvm.llmod(), s)
+    })
+    .expect("non-UTF8 symbol name");
+    let unreachable: FxHashSet<&str> = unreachable.lines().collect();
+
+    // Only the items that upstream crates export are reported, everything
+    // else is an implementation detail of the crate it is in.
+    let exported_symbols = cgcx.exported_symbols.as_ref().expect("needs exported symbols for LTO");
+    let mut by_crate: BTreeMap<(&str, CrateNum), Vec<String>> = BTreeMap::new();
+    for (&cnum, symbols) in exported_symbols.iter() {
+        if cnum == LOCAL_CRATE {
+            continue;
+        }
+        let mut paths: Vec<String> = symbols
+            .iter()
+            .filter(|(name, _)| unreachable.contains(name.as_str()))
+            .map(|(name, _)| match rustc_demangle::try_demangle(name) {
+                Ok(demangled) => format!("{demangled:#}"),
+                Err(_) => name.clone(),
+            })
+            .collect();
+        if !paths.is_empty() {
+            paths.sort();
+            by_crate.insert((&cgcx.crate_names[&cnum], cnum), paths);
+        }
+    }
+
+    for ((krate, _), paths) in by_crate {
+        let items = paths.iter().map(|path| format!("\n    {path}")).collect();
+        let krate = krate.to_string();
+        diag_handler.emit_note(UnreachableItems { krate, count: paths.len(), items });
+    }
+}
+
 pub struct ModuleBuffer(&'static mut llvm::ModuleBuffer);
 
 unsafe impl Send for ModuleBuffer {}
@@ -212,7 +212,9 @@ pub fn target_machine_factory(
     let abi = SmallCStr::new(&sess.target.llvm_abiname);
     let trap_unreachable =
         sess.opts.unstable_opts.trap_unreachable.unwrap_or(sess.target.trap_unreachable);
-    let emit_stack_size_section = sess.opts.unstable_opts.emit_stack_sizes;
+    // `--print stack-usage` reads the frame sizes back from the linked output.
+    let emit_stack_size_section = sess.opts.unstable_opts.emit_stack_sizes
+        || sess.opts.prints.iter().any(|p| p.kind == config::PrintKind::StackUsage);
 
     let asm_comments = sess.opts.unstable_opts.asm_comments;
     let relax_elf_relocations =
@@ -219,6 +219,15 @@ pub(crate) struct FromLlvmOptimizationDiag<'a> {
     pub message: &'a str,
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_llvm_unreachable_items)]
+pub(crate) struct UnreachableItems {
+    pub krate: String,
+    pub count: usize,
+    /// One line per item, each starting with a newline.
+    pub items: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(codegen_llvm_from_llvm_diag)]
 pub(crate) struct FromLlvmDiag {
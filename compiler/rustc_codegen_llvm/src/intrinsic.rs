@@ -222,7 +222,8 @@ fn codegen_intrinsic_call(
             | sym::rotate_left
             | sym::rotate_right
             | sym::saturating_add
-            | sym::saturating_sub => {
+            | sym::saturating_sub
+            | sym::value_barrier => {
                 let ty = arg_tys[0];
                 match int_type_width_signed(ty, self) {
                     Some((width, signed)) => match name {
@@ -282,6 +283,56 @@ fn codegen_intrinsic_call(
                             );
                             self.call_intrinsic(llvm_name, &[lhs, rhs])
                         }
+                        sym::value_barrier => {
+                            // Passing the value through an empty inline assembly block that
+                            // LLVM can't look into hides where it came from, so e.g. a mask
+                            // computed from a comparison is not turned back into a branch or
+                            // a `select`. The asm has no side effects, so it still goes away
+                            // if the result is unused.
+                            let reg_width = u64::from(self.cx.sess().target.pointer_width);
+                            if width <= reg_width {
+                                let reg = self.type_ix(reg_width);
+                                let val = self.intcast(args[0].immediate(), reg, signed);
+                                let val = crate::asm::inline_asm_call(
+                                    self,
+                                    "",
+                                    "=r,0",
+                                    &[val],
+                                    reg,
+                                    false,
+                                    false,
+                                    llvm::AsmDialect::Att,
+                                    &[span],
+                                    false,
+                                    None,
+                                )
+                                .unwrap_or_else(|| {
+                                    bug!("failed to generate inline asm call for `value_barrier`")
+                                });
+                                self.intcast(val, self.type_ix(width), signed)
+                            } else {
+                                // Wider than a register, so go through memory instead, like
+                                // `black_box` does.
+                                args[0].val.store(self, result);
+                                crate::asm::inline_asm_call(
+                                    self,
+                                    "",
+                                    "r,~{memory}",
+                                    &[result.llval],
+                                    self.type_void(),
+                                    true,
+                                    false,
+                                    llvm::AsmDialect::Att,
+                                    &[span],
+                                    false,
+                                    None,
+                                )
+                                .unwrap_or_else(|| {
+                                    bug!("failed to generate inline asm call for `value_barrier`")
+                                });
+                                return;
+                            }
+                        }
                         _ => bug!(),
                     },
                     None => {
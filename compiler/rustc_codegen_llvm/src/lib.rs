@@ -233,6 +233,9 @@ fn optimize_fat(
         module: &mut ModuleCodegen<Self::Module>,
     ) -> Result<(), FatalError> {
         let diag_handler = cgcx.create_diag_handler();
+        if cgcx.opts.unstable_opts.print_unreachable_items {
+            back::lto::print_unreachable_items(cgcx, &diag_handler, module);
+        }
         back::lto::run_pass_manager(cgcx, &diag_handler, module, false)
     }
     unsafe fn optimize_thin(
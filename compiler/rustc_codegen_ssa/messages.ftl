@@ -151,6 +151,8 @@ codegen_ssa_lib_def_write_failure = failed to write lib.def file: {$error}
 
 codegen_ssa_link_exe_unexpected_error = `link.exe` returned an unexpected error
 
+codegen_ssa_link_map_unsupported = the linker for this target cannot write a link map, ignoring `--emit=link-map`
+
 codegen_ssa_link_script_unavailable = can only use link script when linking with GNU-like linker
 
 codegen_ssa_link_script_write_failure = failed to write link script to {$path}: {$error}
@@ -207,12 +209,22 @@ codegen_ssa_rlib_not_found = could not find rlib for: `{$crate_name}`
 
 codegen_ssa_rlib_only_rmeta_found = could not find rlib for: `{$crate_name}`, found rmeta (metadata) file
 
+codegen_ssa_section_sizes_unreadable_output = failed to read `{$path}` to check the sizes of its sections: {$error}
+
+codegen_ssa_section_too_large = section `{$section}` of `{$path}` is {$size} bytes, which exceeds its limit of {$limit} bytes
+    .note = the largest symbols in the section are: {$symbols}
+
 codegen_ssa_select_cpp_build_tool_workload = in the Visual Studio installer, ensure the "C++ build tools" workload is selected
 
 codegen_ssa_shuffle_indices_evaluation = could not evaluate shuffle_indices at compile time
 
 codegen_ssa_specify_libraries_to_link = use the `-l` flag to specify native libraries to link
 
+codegen_ssa_stack_usage_no_frame_sizes = no frame sizes found in `{$path}`, stack usage cannot be computed
+    .note = the linked output must keep its symbol table and the `.stack_sizes` section
+
+codegen_ssa_stack_usage_unreadable_output = failed to read `{$path}` to compute stack usage: {$error}
+
 codegen_ssa_static_library_native_artifacts = Link against the following native artifacts when linking against this static library. The order and any duplication can be significant on some platforms.
 
 codegen_ssa_static_library_native_artifacts_to_file = Native artifacts to link against have been written to {$path}. The order and any duplication can be significant on some platforms.
@@ -33,6 +33,8 @@
 use super::linker::{self, Linker};
 use super::metadata::{create_wrapper_file, MetadataPosition};
 use super::rpath::{self, RPathConfig};
+use super::section_sizes;
+use super::stack_usage;
 use crate::{
     errors, looks_like_rust_object_file, CodegenResults, CompiledModule, CrateInfo, NativeLib,
 };
@@ -109,6 +111,13 @@ pub fn link_binary<'a>(
             let crate_name = format!("{}", codegen_results.crate_info.local_crate_name);
             let out_filename =
                 output.file_for_writing(outputs, OutputType::Exe, Some(crate_name.as_str()));
+            let link_map_output = outputs
+                .outputs
+                .contains_key(&OutputType::LinkMap)
+                .then(|| outputs.path(OutputType::LinkMap));
+            let link_map = link_map_output.as_ref().map(|output| {
+                output.file_for_writing(outputs, OutputType::LinkMap, Some(crate_name.as_str()))
+            });
             match crate_type {
                 CrateType::Rlib => {
                     let _timer = sess.timer("link_rlib");
@@ -137,9 +146,22 @@ pub fn link_binary<'a>(
                         archive_builder_builder,
                         crate_type,
                         &out_filename,
+                        link_map.as_deref(),
                         codegen_results,
                         path.as_ref(),
                     )?;
+                    if let (Some(output), Some(link_map)) = (&link_map_output, link_map) {
+                        if output.is_stdout() {
+                            if let Err(e) = copy_to_stdout(&link_map) {
+                                sess.emit_err(errors::CopyPath::new(
+                                    &link_map,
+                                    output.as_path(),
+                                    e,
+                                ));
+                            }
+                            tempfiles_for_stdout_output.push(link_map);
+                        }
+                    }
                 }
             }
             if sess.opts.json_artifact_notifications {
@@ -725,6 +747,7 @@ fn link_natively<'a>(
     archive_builder_builder: &dyn ArchiveBuilderBuilder,
     crate_type: CrateType,
     out_filename: &Path,
+    link_map: Option<&Path>,
     codegen_results: &CodegenResults,
     tmpdir: &Path,
 ) -> Result<(), ErrorGuaranteed> {
@@ -739,6 +762,7 @@ fn link_natively<'a>(
         crate_type,
         tmpdir,
         out_filename,
+        link_map,
         codegen_results,
         self_contained_components,
     )?;
@@ -1033,6 +1057,20 @@ fn is_illegal_instruction(_status: &ExitStatus) -> bool {
         SplitDebuginfo::Packed => link_dwarf_object(sess, codegen_results, out_filename),
     }
 
+    // This needs the symbol table, so it has to happen before stripping.
+    if let Some(graph) = &codegen_results.crate_info.mono_call_graph {
+        for print in &sess.opts.prints {
+            if print.kind == PrintKind::StackUsage {
+                stack_usage::print_stack_usage(sess, print, graph, out_filename);
+            }
+        }
+    }
+
+    // So does pointing out the largest symbols in sections that are too large.
+    if !sess.opts.unstable_opts.max_section_size.is_empty() {
+        section_sizes::check_section_sizes(sess, out_filename);
+    }
+
     let strip = sess.opts.cg.strip;
 
     if sess.target.is_like_osx {
@@ -1940,8 +1978,19 @@ fn add_linked_symbol_object(
 }
 
 /// Add object files containing code from the current crate.
-fn add_local_crate_regular_objects(cmd: &mut dyn Linker, codegen_results: &CodegenResults) {
-    for obj in codegen_results.modules.iter().filter_map(|m| m.object.as_ref()) {
+fn add_local_crate_regular_objects(
+    cmd: &mut dyn Linker,
+    sess: &Session,
+    codegen_results: &CodegenResults,
+) {
+    let mut objects: Vec<&PathBuf> =
+        codegen_results.modules.iter().filter_map(|m| m.object.as_ref()).collect();
+    if sess.opts.unstable_opts.deterministic_output {
+        // The object files are named after their codegen units, whose names only depend on the
+        // crate, so this order does not depend on how codegen happened to be scheduled.
+        objects.sort();
+    }
+    for obj in objects {
         cmd.add_object(obj);
     }
 }
@@ -2044,6 +2093,7 @@ fn linker_with_args<'a>(
     crate_type: CrateType,
     tmpdir: &Path,
     out_filename: &Path,
+    link_map: Option<&Path>,
     codegen_results: &CodegenResults,
     self_contained_components: LinkSelfContainedComponents,
 ) -> Result<Command, ErrorGuaranteed> {
@@ -2118,7 +2168,7 @@ fn linker_with_args<'a>(
     // link line. And finally upstream native libraries can't depend on anything
     // in this DAG so far because they can only depend on other native libraries
     // and such dependencies are also required to be specified.
-    add_local_crate_regular_objects(cmd, codegen_results);
+    add_local_crate_regular_objects(cmd, sess, codegen_results);
     add_local_crate_metadata_objects(cmd, crate_type, codegen_results);
     add_local_crate_allocator_objects(cmd, codegen_results);
 
@@ -2288,6 +2338,10 @@ fn add_order_independent_options(
     // Make the binary compatible with data execution prevention schemes.
     cmd.add_no_exec();
 
+    if sess.opts.unstable_opts.deterministic_output {
+        cmd.deterministic_output();
+    }
+
     if self_contained_components.is_crt_objects_enabled() {
         cmd.no_crt_objects();
     }
@@ -2322,6 +2376,9 @@ fn add_order_independent_options(
     add_library_search_dirs(cmd, sess, self_contained_components.are_any_components_enabled());
 
     cmd.output_filename(out_filename);
+    if let Some(link_map) = link_map {
+        cmd.link_map(link_map);
+    }
 
     if crate_type == CrateType::Executable && sess.target.is_like_windows {
         if let Some(ref s) = codegen_results.crate_info.windows_subsystem {
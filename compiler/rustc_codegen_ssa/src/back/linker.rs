@@ -176,6 +176,7 @@ pub trait Linker {
     fn include_path(&mut self, path: &Path);
     fn framework_path(&mut self, path: &Path);
     fn output_filename(&mut self, path: &Path);
+    fn link_map(&mut self, path: &Path);
     fn add_object(&mut self, path: &Path);
     fn gc_sections(&mut self, keep_metadata: bool);
     fn no_gc_sections(&mut self);
@@ -194,6 +195,7 @@ pub trait Linker {
     fn add_eh_frame_header(&mut self) {}
     fn add_no_exec(&mut self) {}
     fn add_as_needed(&mut self) {}
+    fn deterministic_output(&mut self) {}
     fn reset_per_library_state(&mut self) {}
 }
 
@@ -473,6 +475,17 @@ fn framework_path(&mut self, path: &Path) {
     fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
+    fn link_map(&mut self, path: &Path) {
+        if self.sess.target.is_like_osx {
+            self.linker_args(&[OsStr::new("-map"), path.as_os_str()]);
+        } else if self.sess.target.is_like_solaris {
+            self.sess.emit_warning(errors::LinkMapUnsupported);
+        } else {
+            let mut arg = OsString::from("-Map=");
+            arg.push(path);
+            self.linker_arg(arg);
+        }
+    }
     fn add_object(&mut self, path: &Path) {
         self.cmd.arg(path);
     }
@@ -782,6 +795,14 @@ fn add_as_needed(&mut self) {
             self.linker_args(&["-z", "ignore"]);
         }
     }
+
+    // Some toolchains make the linker emit a build ID by default, and the order of input
+    // sections otherwise depends on the order the linker happens to visit them in.
+    fn deterministic_output(&mut self) {
+        if self.is_gnu && !self.sess.target.is_like_windows {
+            self.linker_args(&["--build-id=none", "--sort-section=name"]);
+        }
+    }
 }
 
 pub struct MsvcLinker<'a> {
@@ -887,6 +908,12 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg(&arg);
     }
 
+    fn link_map(&mut self, path: &Path) {
+        let mut arg = OsString::from("/MAP:");
+        arg.push(path);
+        self.cmd.arg(&arg);
+    }
+
     fn framework_path(&mut self, _path: &Path) {
         bug!("frameworks are not supported on windows")
     }
@@ -1028,6 +1055,11 @@ fn linker_plugin_lto(&mut self) {
     fn add_no_exec(&mut self) {
         self.cmd.arg("/NXCOMPAT");
     }
+
+    fn deterministic_output(&mut self) {
+        // Replace the timestamps in the output with a hash of its contents.
+        self.cmd.arg("/Brepro");
+    }
 }
 
 pub struct EmLinker<'a> {
@@ -1054,6 +1086,10 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
 
+    fn link_map(&mut self, _path: &Path) {
+        self.sess.emit_warning(errors::LinkMapUnsupported);
+    }
+
     fn add_object(&mut self, path: &Path) {
         self.cmd.arg(path);
     }
@@ -1256,6 +1292,12 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
 
+    fn link_map(&mut self, path: &Path) {
+        let mut arg = OsString::from("-Map=");
+        arg.push(path);
+        self.cmd.arg(&arg);
+    }
+
     fn add_object(&mut self, path: &Path) {
         self.cmd.arg(path);
     }
@@ -1374,6 +1416,10 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
 
+    fn link_map(&mut self, _path: &Path) {
+        self.sess.emit_warning(errors::LinkMapUnsupported);
+    }
+
     fn add_object(&mut self, path: &Path) {
         self.cmd.arg(path);
     }
@@ -1551,6 +1597,10 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
 
+    fn link_map(&mut self, _path: &Path) {
+        self.sess.emit_warning(errors::LinkMapUnsupported);
+    }
+
     fn add_object(&mut self, path: &Path) {
         self.cmd.arg(path);
     }
@@ -1793,6 +1843,10 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
 
+    fn link_map(&mut self, _path: &Path) {
+        self.sess.emit_warning(errors::LinkMapUnsupported);
+    }
+
     fn link_dylib(&mut self, _lib: &str, _verbatim: bool, _as_needed: bool) {
         panic!("external dylibs not supported")
     }
@@ -1889,6 +1943,10 @@ fn output_filename(&mut self, path: &Path) {
         self.cmd.arg("-o").arg(path);
     }
 
+    fn link_map(&mut self, _path: &Path) {
+        self.sess.emit_warning(errors::LinkMapUnsupported);
+    }
+
     fn link_dylib(&mut self, _lib: &str, _verbatim: bool, _as_needed: bool) {
         panic!("external dylibs not supported")
     }
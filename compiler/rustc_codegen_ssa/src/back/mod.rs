@@ -5,5 +5,7 @@
 pub mod lto;
 pub mod metadata;
 pub mod rpath;
+mod section_sizes;
+mod stack_usage;
 pub mod symbol_export;
 pub mod write;
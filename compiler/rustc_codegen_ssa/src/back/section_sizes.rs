@@ -0,0 +1,76 @@
+//! Enforcement of the section size limits given with `-Z max-section-size`.
+//!
+//! The sizes are read back from the linked output, so they account for
+//! everything the linker put in there, including upstream crates and native
+//! libraries. When a section is too large, the symbols in it are used to point
+//! out what takes up the most space.
+
+use std::fs;
+use std::path::Path;
+
+use object::{Object, ObjectSection, ObjectSymbol, SectionIndex};
+use rustc_demangle::demangle;
+use rustc_session::Session;
+
+use crate::errors;
+
+/// How many symbols are listed for a section that is too large.
+const LARGEST_SYMBOLS: usize = 10;
+
+pub(super) fn check_section_sizes(sess: &Session, out_filename: &Path) {
+    let data = match fs::read(out_filename) {
+        Ok(data) => data,
+        Err(error) => {
+            let error = error.to_string();
+            sess.emit_err(errors::SectionSizesUnreadableOutput { path: out_filename, error });
+            return;
+        }
+    };
+    let file = match object::File::parse(&*data) {
+        Ok(file) => file,
+        Err(error) => {
+            let error = error.to_string();
+            sess.emit_err(errors::SectionSizesUnreadableOutput { path: out_filename, error });
+            return;
+        }
+    };
+
+    for (name, limit) in &sess.opts.unstable_opts.max_section_size {
+        // A name can be shared by several sections, e.g. in relocatable output.
+        let sections: Vec<_> =
+            file.sections().filter(|section| section.name().ok() == Some(name)).collect();
+        let size = sections.iter().map(|section| section.size()).sum::<u64>();
+        if size <= *limit {
+            continue;
+        }
+
+        let indices: Vec<SectionIndex> = sections.iter().map(|section| section.index()).collect();
+        let mut symbols: Vec<_> = file
+            .symbols()
+            .filter(|symbol| symbol.size() > 0)
+            .filter(|symbol| symbol.section_index().is_some_and(|index| indices.contains(&index)))
+            .filter_map(|symbol| Some((symbol.name().ok()?, symbol.size())))
+            .collect();
+        symbols.sort_by(|(a_name, a_size), (b_name, b_size)| {
+            b_size.cmp(a_size).then_with(|| a_name.cmp(b_name))
+        });
+        let symbols = if symbols.is_empty() {
+            "no sized symbols in this section".to_string()
+        } else {
+            symbols
+                .iter()
+                .take(LARGEST_SYMBOLS)
+                .map(|(name, size)| format!("`{:#}` ({size} bytes)", demangle(name)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        sess.emit_err(errors::SectionTooLarge {
+            path: out_filename,
+            section: name,
+            size,
+            limit: *limit,
+            symbols,
+        });
+    }
+}
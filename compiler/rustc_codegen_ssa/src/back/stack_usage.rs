@@ -0,0 +1,249 @@
+//! Worst-case stack usage of a linked binary, for `--print stack-usage`.
+//!
+//! The frame size of every function is read back from the `.stack_sizes`
+//! section that LLVM emits (see `-Z emit-stack-sizes`), and combined with the
+//! call graph of the local crate computed by `rustc_monomorphize`. The depth
+//! reported for each root is the largest sum of frame sizes along any call path
+//! starting from it.
+//!
+//! Recursion and calls through function pointers or trait objects make the
+//! depth unbounded. Functions that are not part of the local call graph, such
+//! as non-generic functions of upstream crates, are counted with their own
+//! frame size only, so the result for any root reaching them is a lower bound.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use object::{Object, ObjectSection, ObjectSymbol, SymbolKind};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::stack::ensure_sufficient_stack;
+use rustc_middle::mir::mono::MonoCallGraph;
+use rustc_session::config::PrintRequest;
+use rustc_session::Session;
+
+use crate::errors;
+
+const STACK_SIZES_SECTION: &str = ".stack_sizes";
+
+pub(super) fn print_stack_usage(
+    sess: &Session,
+    print: &PrintRequest,
+    graph: &MonoCallGraph,
+    out_filename: &Path,
+) {
+    let frame_sizes = match read_frame_sizes(out_filename) {
+        Ok(frame_sizes) => frame_sizes,
+        Err(error) => {
+            sess.emit_warning(errors::StackUsageUnreadableOutput { path: out_filename, error });
+            return;
+        }
+    };
+    if frame_sizes.is_empty() {
+        sess.emit_warning(errors::StackUsageNoFrameSizes { path: out_filename });
+        return;
+    }
+
+    let mut cx = StackUsageCx {
+        graph,
+        frame_sizes: &frame_sizes,
+        results: FxHashMap::default(),
+        path: Vec::new(),
+    };
+
+    let mut content = String::new();
+    for root in &graph.roots {
+        let usage = cx.usage(root);
+        cx.describe(root, &usage, &mut content);
+    }
+    print.out.overwrite(&content, sess);
+}
+
+/// Reads the frame size of every function symbol from the `.stack_sizes`
+/// section(s) of the linked output.
+fn read_frame_sizes(path: &Path) -> Result<FxHashMap<String, u64>, String> {
+    let data = fs::read(path).map_err(|e| e.to_string())?;
+    let file = object::File::parse(&*data).map_err(|e| e.to_string())?;
+
+    let address_size = if file.is_64() { 8 } else { 4 };
+    let mut sizes_by_address = FxHashMap::default();
+    for section in file.sections() {
+        if section.name().ok() != Some(STACK_SIZES_SECTION) {
+            continue;
+        }
+        let mut data = section.data().map_err(|e| e.to_string())?;
+        // Each entry is the address of a function followed by its frame size
+        // encoded as ULEB128.
+        while data.len() >= address_size {
+            let (address, rest) = data.split_at(address_size);
+            let address = if address_size == 8 {
+                let address = address.try_into().unwrap();
+                if file.is_little_endian() {
+                    u64::from_le_bytes(address)
+                } else {
+                    u64::from_be_bytes(address)
+                }
+            } else {
+                let address = address.try_into().unwrap();
+                if file.is_little_endian() {
+                    u32::from_le_bytes(address).into()
+                } else {
+                    u32::from_be_bytes(address).into()
+                }
+            };
+            let Some((size, rest)) = read_uleb128(rest) else {
+                return Err(format!("malformed `{STACK_SIZES_SECTION}` section"));
+            };
+            sizes_by_address.insert(address, size);
+            data = rest;
+        }
+    }
+
+    let mut frame_sizes = FxHashMap::default();
+    for symbol in file.symbols() {
+        if symbol.kind() != SymbolKind::Text {
+            continue;
+        }
+        let (Ok(name), Some(&size)) = (symbol.name(), sizes_by_address.get(&symbol.address()))
+        else {
+            continue;
+        };
+        frame_sizes.insert(name.to_string(), size);
+    }
+    Ok(frame_sizes)
+}
+
+fn read_uleb128(data: &[u8]) -> Option<(u64, &[u8])> {
+    let mut result = 0;
+    let mut shift = 0;
+    for (i, &byte) in data.iter().enumerate() {
+        if shift >= 64 {
+            return None;
+        }
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some((result, &data[i + 1..]));
+        }
+        shift += 7;
+    }
+    None
+}
+
+#[derive(Clone)]
+enum StackUsage<'a> {
+    Bounded {
+        bytes: u64,
+        /// Whether every function reachable from this one is part of the call
+        /// graph, i.e. whether `bytes` is exact rather than a lower bound.
+        complete: bool,
+        /// The callee on the deepest call path, if any.
+        deepest_callee: Option<&'a str>,
+    },
+    /// The function is part of, or reaches, a cycle in the call graph.
+    Recursive { cycle: Vec<&'a str> },
+    /// The function reaches `caller`, which calls through a function pointer or
+    /// a trait object.
+    IndirectCall { caller: &'a str },
+}
+
+struct StackUsageCx<'a> {
+    graph: &'a MonoCallGraph,
+    frame_sizes: &'a FxHashMap<String, u64>,
+    results: FxHashMap<&'a str, StackUsage<'a>>,
+    /// The call path that is currently being explored, to detect recursion.
+    path: Vec<&'a str>,
+}
+
+impl<'a> StackUsageCx<'a> {
+    fn usage(&mut self, symbol: &'a str) -> StackUsage<'a> {
+        if let Some(usage) = self.results.get(symbol) {
+            return usage.clone();
+        }
+        if let Some(start) = self.path.iter().position(|&s| s == symbol) {
+            let mut cycle = self.path[start..].to_vec();
+            cycle.push(symbol);
+            return StackUsage::Recursive { cycle };
+        }
+
+        // Functions without a recorded frame size were either inlined into
+        // their callers by LLVM, in which case their frame is part of the
+        // caller's, or come from code that was not compiled with stack sizes.
+        let frame_size = self.frame_sizes.get(symbol).copied().unwrap_or(0);
+        let usage = match self.graph.nodes.get(symbol) {
+            None => {
+                StackUsage::Bounded { bytes: frame_size, complete: false, deepest_callee: None }
+            }
+            Some(node) if node.has_indirect_calls => StackUsage::IndirectCall { caller: symbol },
+            Some(node) => {
+                self.path.push(symbol);
+                let mut deepest = 0;
+                let mut complete = true;
+                let mut deepest_callee = None;
+                let mut unbounded = None;
+                for callee in node.callees.iter().map(String::as_str) {
+                    match ensure_sufficient_stack(|| self.usage(callee)) {
+                        StackUsage::Bounded { bytes, complete: callee_complete, .. } => {
+                            complete &= callee_complete;
+                            if deepest_callee.is_none() || bytes > deepest {
+                                deepest = bytes;
+                                deepest_callee = Some(callee);
+                            }
+                        }
+                        usage => {
+                            unbounded = Some(usage);
+                            break;
+                        }
+                    }
+                }
+                self.path.pop();
+                unbounded.unwrap_or(StackUsage::Bounded {
+                    bytes: frame_size + deepest,
+                    complete,
+                    deepest_callee,
+                })
+            }
+        };
+        self.results.insert(symbol, usage.clone());
+        usage
+    }
+
+    fn describe(&self, root: &str, usage: &StackUsage<'_>, out: &mut String) {
+        let root_name = rustc_demangle::demangle(root);
+        match usage {
+            StackUsage::Bounded { bytes, complete, .. } => {
+                let at_least = if *complete { "" } else { "at least " };
+                writeln!(out, "{root_name:#}: {at_least}{bytes} bytes").unwrap();
+
+                let mut next = Some(root);
+                while let Some(symbol) = next {
+                    let name = rustc_demangle::demangle(symbol);
+                    match self.frame_sizes.get(symbol) {
+                        Some(frame_size) => writeln!(out, "    {frame_size:>8} {name:#}").unwrap(),
+                        None => writeln!(out, "    {:>8} {name:#}", "?").unwrap(),
+                    }
+                    next = match self.results.get(symbol) {
+                        Some(StackUsage::Bounded { deepest_callee, .. }) => *deepest_callee,
+                        _ => None,
+                    };
+                }
+            }
+            StackUsage::Recursive { cycle } => {
+                writeln!(out, "{root_name:#}: unbounded").unwrap();
+                let cycle = cycle
+                    .iter()
+                    .map(|symbol| format!("{:#}", rustc_demangle::demangle(symbol)))
+                    .collect::<Vec<_>>();
+                writeln!(out, "    recursion: {}", cycle.join(" -> ")).unwrap();
+            }
+            StackUsage::IndirectCall { caller } => {
+                writeln!(out, "{root_name:#}: unbounded").unwrap();
+                writeln!(
+                    out,
+                    "    indirect call through a function pointer or trait object in {:#}",
+                    rustc_demangle::demangle(caller)
+                )
+                .unwrap();
+            }
+        }
+    }
+}
@@ -32,7 +32,7 @@
 use rustc_middle::query::Providers;
 use rustc_middle::ty::layout::{HasTyCtxt, LayoutOf, TyAndLayout};
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
-use rustc_session::config::{self, CrateType, EntryFnType, OutputType};
+use rustc_session::config::{self, CrateType, EntryFnType, OutputType, PrintKind};
 use rustc_session::Session;
 use rustc_span::symbol::sym;
 use rustc_span::Symbol;
@@ -846,6 +846,14 @@ pub fn new(tcx: TyCtxt<'_>, target_cpu: String) -> CrateInfo {
         // `compiler_builtins` are always placed last to ensure that they're linked correctly.
         used_crates.extend(compiler_builtins);
 
+        let mono_call_graph = tcx
+            .sess
+            .opts
+            .prints
+            .iter()
+            .any(|p| p.kind == PrintKind::StackUsage)
+            .then(|| tcx.mono_call_graph(()).clone());
+
         let mut info = CrateInfo {
             target_cpu,
             crate_types,
@@ -863,6 +871,7 @@ pub fn new(tcx: TyCtxt<'_>, target_cpu: String) -> CrateInfo {
             dependency_formats: tcx.dependency_formats(()).clone(),
             windows_subsystem,
             natvis_debugger_visualizers: Default::default(),
+            mono_call_graph,
         };
         let crates = tcx.crates(());
 
@@ -1,5 +1,5 @@
 use rustc_ast::{ast, attr, MetaItemKind, NestedMetaItem};
-use rustc_attr::{list_contains_name, InlineAttr, InstructionSetAttr, OptimizeAttr};
+use rustc_attr::{list_contains_name, InlineAttr, InstructionSetAttr, MirInlineAttr, OptimizeAttr};
 use rustc_errors::struct_span_err;
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
@@ -528,6 +528,14 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, did: LocalDefId) -> CodegenFnAttrs {
         }
     });
 
+    // Malformed `#[mir_inline]` attributes are reported by `rustc_passes`.
+    codegen_fn_attrs.mir_inline = attrs
+        .iter()
+        .filter(|attr| attr.has_name(sym::mir_inline))
+        .filter_map(rustc_attr::parse_mir_inline)
+        .last()
+        .unwrap_or(MirInlineAttr::None);
+
     // #73631: closures inherit `#[target_feature]` annotations
     //
     // If this closure is marked `#[inline(always)]`, simply skip adding `#[target_feature]`.
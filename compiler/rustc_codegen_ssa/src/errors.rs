@@ -111,6 +111,10 @@ pub struct SymbolFileWriteFailure {
 #[diag(codegen_ssa_linker_unsupported_modifier)]
 pub struct LinkerUnsupportedModifier;
 
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_link_map_unsupported)]
+pub struct LinkMapUnsupported;
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_L4Bender_exporting_symbols_unimplemented)]
 pub struct L4BenderExportingSymbolsUnimplemented;
@@ -497,6 +501,38 @@ pub struct UnableToRunDsymutil {
     pub error: Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_stack_usage_unreadable_output)]
+pub struct StackUsageUnreadableOutput<'a> {
+    pub path: &'a Path,
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_stack_usage_no_frame_sizes)]
+#[note]
+pub struct StackUsageNoFrameSizes<'a> {
+    pub path: &'a Path,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_section_sizes_unreadable_output)]
+pub struct SectionSizesUnreadableOutput<'a> {
+    pub path: &'a Path,
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_section_too_large)]
+#[note]
+pub struct SectionTooLarge<'a> {
+    pub path: &'a Path,
+    pub section: &'a str,
+    pub size: u64,
+    pub limit: u64,
+    pub symbols: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_stripping_debug_info_failed)]
 #[note]
@@ -439,7 +439,7 @@ pub fn emulate_intrinsic(
                     /*allow_transmute*/ false,
                 )?;
             }
-            sym::likely | sym::unlikely | sym::black_box => {
+            sym::likely | sym::unlikely | sym::black_box | sym::value_barrier => {
                 // These just return their argument
                 self.copy_op(&args[0], dest, /*allow_transmute*/ false)?;
             }
@@ -759,9 +759,14 @@ fn print_crate_info(
     #[allow(unused_imports)]
     use {do_not_use_safe_print as safe_print, do_not_use_safe_print as safe_println};
 
-    // NativeStaticLibs and LinkArgs are special - printed during linking
+    // NativeStaticLibs, LinkArgs and StackUsage are special - printed during linking
     // (empty iterator returns true)
-    if sess.opts.prints.iter().all(|p| p.kind == NativeStaticLibs || p.kind == LinkArgs) {
+    if sess
+        .opts
+        .prints
+        .iter()
+        .all(|p| p.kind == NativeStaticLibs || p.kind == LinkArgs || p.kind == StackUsage)
+    {
         return Compilation::Continue;
     }
 
@@ -876,6 +881,17 @@ fn print_crate_info(
             // Any output here interferes with Cargo's parsing of other printed output
             NativeStaticLibs => {}
             LinkArgs => {}
+            StackUsage => {}
+            StdCapabilities => {
+                if !sess.target.has_std() {
+                    println_info!("std=unavailable");
+                } else {
+                    for facility in rustc_target::spec::STD_FACILITIES {
+                        let status = sess.target.std_facility_status(facility);
+                        println_info!("{facility}={}", status.desc());
+                    }
+                }
+            }
             SplitDebuginfo => {
                 use rustc_target::spec::SplitDebuginfo::{Off, Packed, Unpacked};
 
@@ -1009,7 +1025,9 @@ pub fn describe_lints(sess: &Session, lint_store: &LintStore, loaded_lints: bool
 
     fn sort_lints(sess: &Session, mut lints: Vec<&'static Lint>) -> Vec<&'static Lint> {
         // The sort doesn't case-fold but it's doubtful we care.
-        lints.sort_by_cached_key(|x: &&Lint| (x.default_level(sess.edition()), x.name));
+        lints.sort_by_cached_key(|x: &&Lint| {
+            (x.default_level(sess.edition(), &sess.target.os), x.name)
+        });
         lints
     }
 
@@ -1049,7 +1067,7 @@ fn sort_lint_groups(
             safe_println!(
                 "    {}  {:7.7}  {}",
                 padded(&name),
-                lint.default_level(sess.edition()).as_str(),
+                lint.default_level(sess.edition(), &sess.target.os).as_str(),
                 lint.desc
             );
         }
@@ -215,6 +215,11 @@ fn should_show_explain(&self) -> bool {
         true
     }
 
+    /// Checks if should show the summary of how many errors and warnings were emitted
+    fn should_show_error_count(&self) -> bool {
+        true
+    }
+
     /// Checks if we can use colors in the current output stream.
     fn supports_color(&self) -> bool {
         false
@@ -0,0 +1,287 @@
+//! Turning machine-applicable suggestions into a patch.
+//!
+//! This is what `-Z emit-fixes` writes: a unified diff of every file touched
+//! by the suggestions, which can be applied with `patch -p1` or `git apply`
+//! from the directory the compiler was run in, without rebuilding anything.
+
+use std::cmp;
+use std::fmt::Write;
+use std::path::Path;
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::Lrc;
+use rustc_span::source_map::SourceMap;
+use rustc_span::{BytePos, SourceFile, Span};
+
+use crate::CodeSuggestion;
+
+/// The number of unchanged lines shown around every change.
+const CONTEXT: usize = 3;
+
+/// A suggestion that was left out of a [`Patch`] because it overlaps with one
+/// that was included.
+pub struct Conflict {
+    /// The part of the suggestion that was left out.
+    pub span: Span,
+    /// The part of an earlier suggestion that it overlaps with.
+    pub other: Span,
+}
+
+pub struct Patch {
+    /// The unified diff. Empty if there is nothing to change.
+    pub diff: String,
+    pub conflicts: Vec<Conflict>,
+    /// The files whose suggestions were left out because the compiler changed
+    /// their line endings or removed their byte order mark when reading them,
+    /// so that a diff against the source it has would not apply.
+    pub normalized_files: Vec<String>,
+}
+
+struct Edit {
+    /// Byte range in the source file.
+    lo: usize,
+    hi: usize,
+    snippet: String,
+    span: Span,
+}
+
+impl Edit {
+    fn overlaps(&self, other: &Edit) -> bool {
+        // Two insertions at the same place conflict as well, as there is no way
+        // to tell which should go first.
+        (self.lo < other.hi && other.lo < self.hi)
+            || (self.lo == self.hi && other.lo == other.hi && self.lo == other.lo)
+    }
+
+    fn same_change(&self, other: &Edit) -> bool {
+        self.lo == other.lo && self.hi == other.hi && self.snippet == other.snippet
+    }
+}
+
+/// Builds a patch applying the first alternative of each of the given
+/// suggestions.
+///
+/// The suggestions are taken in order, and a suggestion that overlaps with an
+/// earlier one is left out entirely and reported as a [`Conflict`], unless it
+/// makes exactly the same change. Suggestions for code whose source is not
+/// available, e.g. from other crates, are skipped, and so are suggestions for
+/// files with CRLF line endings or a byte order mark, which are listed in
+/// [`Patch::normalized_files`].
+pub fn make_patch(sm: &SourceMap, suggestions: &[CodeSuggestion]) -> Patch {
+    let mut files: FxIndexMap<BytePos, (Lrc<SourceFile>, Vec<Edit>)> = Default::default();
+    let mut conflicts = Vec::new();
+    let mut normalized_files = Vec::new();
+
+    'suggestions: for suggestion in suggestions {
+        let Some(substitution) = suggestion.substitutions.first() else { continue };
+        let mut edits = Vec::with_capacity(substitution.parts.len());
+        for part in &substitution.parts {
+            let lo = sm.lookup_byte_offset(part.span.lo());
+            let hi = sm.lookup_byte_offset(part.span.hi());
+            if !Lrc::ptr_eq(&lo.sf, &hi.sf) || lo.sf.src.is_none() {
+                continue 'suggestions;
+            }
+            if !lo.sf.normalized_pos.is_empty() {
+                let name = sm.filename_for_diagnostics(&lo.sf.name).to_string();
+                if !normalized_files.contains(&name) {
+                    normalized_files.push(name);
+                }
+                continue 'suggestions;
+            }
+            let edit = Edit {
+                lo: lo.pos.to_usize(),
+                hi: hi.pos.to_usize(),
+                snippet: part.snippet.clone(),
+                span: part.span,
+            };
+            edits.push((lo.sf, edit));
+        }
+
+        for (sf, edit) in &edits {
+            let Some((_, accepted)) = files.get(&sf.start_pos) else { continue };
+            let conflict =
+                accepted.iter().find(|other| edit.overlaps(other) && !edit.same_change(other));
+            if let Some(other) = conflict {
+                conflicts.push(Conflict { span: edit.span, other: other.span });
+                continue 'suggestions;
+            }
+        }
+
+        for (sf, edit) in edits {
+            let (_, accepted) = files.entry(sf.start_pos).or_insert_with(|| (sf, Vec::new()));
+            if !accepted.iter().any(|other| edit.same_change(other)) {
+                accepted.push(edit);
+            }
+        }
+    }
+
+    let mut files: Vec<_> = files
+        .into_values()
+        .map(|(sf, edits)| (sm.filename_for_diagnostics(&sf.name).to_string(), sf, edits))
+        .collect();
+    files.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+    let mut diff = String::new();
+    for (name, sf, mut edits) in files {
+        let src = sf.src.as_deref().unwrap();
+        edits.sort_by_key(|edit| (edit.lo, edit.hi));
+        file_diff(&mut diff, &name, src, &edits);
+    }
+    Patch { diff, conflicts, normalized_files }
+}
+
+/// A run of changed lines.
+struct Block {
+    /// The first and the last changed line, inclusive.
+    first: usize,
+    last: usize,
+    /// The lines that replace them.
+    new: String,
+}
+
+fn file_diff(out: &mut String, name: &str, src: &str, edits: &[Edit]) {
+    let mut lines: Vec<&str> = src.split_inclusive('\n').collect();
+    // An empty line past the end, so that insertions at the very end of the
+    // file have a line to go in.
+    if src.is_empty() || src.ends_with('\n') {
+        lines.push("");
+    }
+    let starts: Vec<usize> = lines
+        .iter()
+        .scan(0, |start, line| {
+            let line_start = *start;
+            *start += line.len();
+            Some(line_start)
+        })
+        .collect();
+    let line_of = |pos: usize| starts.partition_point(|&start| start <= pos) - 1;
+
+    let mut blocks: Vec<(usize, usize, Vec<&Edit>)> = Vec::new();
+    for edit in edits {
+        let first = line_of(edit.lo);
+        let last = if edit.hi > edit.lo { line_of(edit.hi - 1) } else { first };
+        match blocks.last_mut() {
+            Some((_, block_last, block_edits)) if first <= *block_last => {
+                *block_last = cmp::max(*block_last, last);
+                block_edits.push(edit);
+            }
+            _ => blocks.push((first, last, vec![edit])),
+        }
+    }
+    let blocks: Vec<Block> = blocks
+        .into_iter()
+        .map(|(first, last, edits)| {
+            let end = starts[last] + lines[last].len();
+            let mut new = String::new();
+            let mut pos = starts[first];
+            for edit in edits {
+                new.push_str(&src[pos..edit.lo]);
+                new.push_str(&edit.snippet);
+                pos = edit.hi;
+            }
+            new.push_str(&src[pos..end]);
+            Block { first, last, new }
+        })
+        .collect();
+    if blocks.is_empty() {
+        return;
+    }
+
+    if Path::new(name).is_absolute() {
+        writeln!(out, "--- {name}\n+++ {name}").unwrap();
+    } else {
+        writeln!(out, "--- a/{name}\n+++ b/{name}").unwrap();
+    }
+
+    // Blocks whose context would touch go into the same hunk.
+    let mut hunks: Vec<&[Block]> = Vec::new();
+    let mut start = 0;
+    for i in 1..=blocks.len() {
+        if i == blocks.len() || blocks[i].first > blocks[i - 1].last + 2 * CONTEXT + 1 {
+            hunks.push(&blocks[start..i]);
+            start = i;
+        }
+    }
+
+    // How many more lines the new file has than the old one, so far.
+    let mut delta: isize = 0;
+    for hunk in hunks {
+        let first = hunk[0].first.saturating_sub(CONTEXT);
+        let last = cmp::min(hunk[hunk.len() - 1].last + CONTEXT, lines.len() - 1);
+
+        let mut body = String::new();
+        let (mut old_count, mut new_count) = (0, 0);
+        let mut line = first;
+        let mut blocks = hunk.iter().peekable();
+        while line <= last {
+            match blocks.next_if(|block| block.first == line) {
+                Some(block) => {
+                    let old = &lines[block.first..=block.last];
+                    let new: Vec<&str> = block.new.split_inclusive('\n').collect();
+                    // Lines that an edit only touches at their start or end, e.g. when
+                    // inserting whole lines, stay the same.
+                    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+                    let max_suffix = cmp::min(old.len(), new.len()) - prefix;
+                    let suffix = old[prefix..]
+                        .iter()
+                        .rev()
+                        .zip(new[prefix..].iter().rev())
+                        .take(max_suffix)
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    let unchanged = old[..prefix].iter().chain(&old[old.len() - suffix..]);
+                    for (i, line) in old.iter().enumerate() {
+                        if i < prefix {
+                            push_line(&mut body, ' ', line);
+                        } else if i < old.len() - suffix {
+                            old_count += push_line(&mut body, '-', line);
+                        }
+                    }
+                    for line in &new[prefix..new.len() - suffix] {
+                        new_count += push_line(&mut body, '+', line);
+                    }
+                    for line in &old[old.len() - suffix..] {
+                        push_line(&mut body, ' ', line);
+                    }
+                    let count: usize = unchanged.map(|line| usize::from(!line.is_empty())).sum();
+                    old_count += count;
+                    new_count += count;
+                    line = block.last + 1;
+                }
+                None => {
+                    let count = push_line(&mut body, ' ', lines[line]);
+                    old_count += count;
+                    new_count += count;
+                    line += 1;
+                }
+            }
+        }
+
+        // The line numbers are 1-based, except that an empty range is given as
+        // the line before it.
+        let old_start = if old_count == 0 { first } else { first + 1 };
+        let new_first = (first as isize + delta) as usize;
+        let new_start = if new_count == 0 { new_first } else { new_first + 1 };
+        writeln!(out, "@@ -{old_start},{old_count} +{new_start},{new_count} @@").unwrap();
+        out.push_str(&body);
+        delta += new_count as isize - old_count as isize;
+    }
+}
+
+/// Adds `line` to the hunk with the given prefix, and returns how many lines
+/// that was.
+fn push_line(body: &mut String, prefix: char, line: &str) -> usize {
+    // The line past the end of the file.
+    if line.is_empty() {
+        return 0;
+    }
+    body.push(prefix);
+    body.push_str(line);
+    if !line.ends_with('\n') {
+        body.push_str("\n\\ No newline at end of file\n");
+    }
+    1
+}
+
+#[cfg(test)]
+mod tests;
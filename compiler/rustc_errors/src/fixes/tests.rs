@@ -0,0 +1,135 @@
+use super::*;
+
+use crate::{Applicability, Substitution, SubstitutionPart, SuggestionStyle};
+use rustc_span::source_map::FilePathMapping;
+
+const SOURCE: &str = "\
+fn main() {
+    let x = 1;
+    let y = 2;
+}
+";
+
+/// A suggestion replacing each `(lo, hi)` range of `SOURCE` with the string.
+fn suggestion(parts: &[(u32, u32, &str)]) -> CodeSuggestion {
+    let parts = parts
+        .iter()
+        .map(|&(lo, hi, snippet)| SubstitutionPart {
+            span: Span::with_root_ctxt(BytePos(lo), BytePos(hi)),
+            snippet: snippet.to_string(),
+        })
+        .collect();
+    CodeSuggestion {
+        substitutions: vec![Substitution { parts }],
+        msg: "test".into(),
+        style: SuggestionStyle::ShowCode,
+        applicability: Applicability::MachineApplicable,
+    }
+}
+
+fn test_patch(suggestions: &[CodeSuggestion], expected: &str, expected_conflicts: usize) {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = SourceMap::new(FilePathMapping::empty());
+        sm.new_source_file(Path::new("test.rs").to_owned().into(), SOURCE.to_owned());
+        let patch = make_patch(&sm, suggestions);
+        assert_eq!(patch.diff, expected);
+        assert_eq!(patch.conflicts.len(), expected_conflicts);
+    })
+}
+
+#[test]
+fn empty() {
+    test_patch(&[], "", 0);
+}
+
+#[test]
+fn single() {
+    test_patch(
+        &[suggestion(&[(20, 21, "_x")])],
+        "\
+--- a/test.rs
++++ b/test.rs
+@@ -1,4 +1,4 @@
+ fn main() {
+-    let x = 1;
++    let _x = 1;
+     let y = 2;
+ }
+",
+        0,
+    );
+}
+
+#[test]
+fn multiple_parts_and_lines() {
+    test_patch(
+        &[suggestion(&[(20, 21, "_x")]), suggestion(&[(35, 36, "_y"), (39, 40, "3\n    ;")])],
+        "\
+--- a/test.rs
++++ b/test.rs
+@@ -1,4 +1,5 @@
+ fn main() {
+-    let x = 1;
++    let _x = 1;
+-    let y = 2;
++    let _y = 3
++    ;;
+ }
+",
+        0,
+    );
+}
+
+#[test]
+fn conflict() {
+    test_patch(
+        &[
+            suggestion(&[(20, 21, "_x")]),
+            // Overlaps with the first one, so none of its parts are applied.
+            suggestion(&[(35, 36, "_y"), (16, 21, "let mut x")]),
+            // The same as the first one.
+            suggestion(&[(20, 21, "_x")]),
+        ],
+        "\
+--- a/test.rs
++++ b/test.rs
+@@ -1,4 +1,4 @@
+ fn main() {
+-    let x = 1;
++    let _x = 1;
+     let y = 2;
+ }
+",
+        1,
+    );
+}
+
+#[test]
+fn conflicting_insertions() {
+    test_patch(
+        &[suggestion(&[(12, 12, "    // a\n")]), suggestion(&[(12, 12, "    // b\n")])],
+        "\
+--- a/test.rs
++++ b/test.rs
+@@ -1,4 +1,5 @@
+ fn main() {
++    // a
+     let x = 1;
+     let y = 2;
+ }
+",
+        1,
+    );
+}
+
+#[test]
+fn normalized_file() {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = SourceMap::new(FilePathMapping::empty());
+        let src = "fn main() {\r\n    let x = 1;\r\n}\r\n";
+        sm.new_source_file(Path::new("crlf.rs").to_owned().into(), src.to_owned());
+        let patch = make_patch(&sm, &[suggestion(&[(20, 21, "_x")])]);
+        assert_eq!(patch.diff, "");
+        assert_eq!(patch.normalized_files, ["crlf.rs"]);
+    })
+}
@@ -67,10 +67,12 @@
 mod diagnostic_impls;
 pub mod emitter;
 pub mod error;
+pub mod fixes;
 pub mod json;
 mod lock;
 pub mod markdown;
 pub mod registry;
+pub mod sarif;
 mod snippet;
 mod styled_buffer;
 #[cfg(test)]
@@ -463,6 +465,10 @@ struct HandlerInner {
 
     future_breakage_diagnostics: Vec<Diagnostic>,
 
+    /// The machine-applicable suggestions of the emitted diagnostics, if
+    /// [`HandlerFlags::collect_fixes`] is set.
+    fixes: Vec<CodeSuggestion>,
+
     /// The [`Self::unstable_expect_diagnostics`] should be empty when this struct is
     /// dropped. However, it can have values if the compilation is stopped early
     /// or is only partially executed. To avoid ICEs, like in rust#94953 we only
@@ -540,6 +546,9 @@ pub struct HandlerFlags {
     pub deduplicate_diagnostics: bool,
     /// Track where errors are created. Enabled with `-Ztrack-diagnostics`.
     pub track_diagnostics: bool,
+    /// If true, the machine-applicable suggestions of emitted diagnostics are kept.
+    /// (rustc: see `-Z emit-fixes`)
+    pub collect_fixes: bool,
 }
 
 impl Drop for HandlerInner {
@@ -619,6 +628,7 @@ pub fn with_emitter(emitter: Box<DynEmitter>) -> Self {
                 emitted_diagnostics: Default::default(),
                 stashed_diagnostics: Default::default(),
                 future_breakage_diagnostics: Vec::new(),
+                fixes: Vec::new(),
                 check_unstable_expect_diagnostics: false,
                 unstable_expect_diagnostics: Vec::new(),
                 fulfilled_expectations: Default::default(),
@@ -1108,6 +1118,12 @@ pub fn take_future_breakage_diagnostics(&self) -> Vec<Diagnostic> {
         std::mem::take(&mut self.inner.borrow_mut().future_breakage_diagnostics)
     }
 
+    /// Takes the machine-applicable suggestions emitted so far, in the order they were emitted.
+    /// This is always empty unless [`HandlerFlags::collect_fixes`] is set.
+    pub fn take_fixes(&self) -> Vec<CodeSuggestion> {
+        std::mem::take(&mut self.inner.borrow_mut().fixes)
+    }
+
     pub fn abort_if_errors(&self) {
         self.inner.borrow_mut().abort_if_errors()
     }
@@ -1413,6 +1429,14 @@ fn emit_diagnostic(&mut self, diagnostic: &mut Diagnostic) -> Option<ErrorGuaran
                 }
 
                 self.emitter.emit_diagnostic(diagnostic);
+                if self.flags.collect_fixes {
+                    let suggestions = diagnostic.suggestions.iter().flatten();
+                    self.fixes.extend(
+                        suggestions
+                            .filter(|sugg| sugg.applicability == Applicability::MachineApplicable)
+                            .cloned(),
+                    );
+                }
                 if diagnostic.is_error() {
                     self.deduplicated_err_count += 1;
                 } else if let Warning(_) = diagnostic.level {
@@ -1469,7 +1493,7 @@ fn print_error_count(&mut self, registry: &Registry) {
             1 => Cow::from("aborting due to previous error"),
             count => Cow::from(format!("aborting due to {count} previous errors")),
         };
-        if self.treat_err_as_bug() {
+        if self.treat_err_as_bug() || !self.emitter.should_show_error_count() {
             return;
         }
 
@@ -0,0 +1,503 @@
+//! A SARIF emitter for errors.
+//!
+//! [SARIF] is the OASIS standard format for the output of static analysis
+//! tools, which is what code scanning and security review tooling consumes.
+//! This emits version 2.1.0 of it.
+//!
+//! Unlike the JSON emitter, which writes one object per diagnostic, a SARIF
+//! log is a single document. The results are therefore collected as the
+//! diagnostics come in and written out when the emitter is dropped, even if
+//! there were none. The summary of how many errors and warnings there were is
+//! left out, as it isn't a result of its own.
+//!
+//! Diagnostics map to results as follows:
+//! - error codes and lint names become the rule of the result, with a link to
+//!   their documentation where there is one,
+//! - primary spans become the locations of the result, and secondary spans as
+//!   well as the spans of child diagnostics become related locations,
+//! - child diagnostics without a span are appended to the message,
+//! - every alternative of every suggestion becomes a fix.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use rustc_span::source_map::SourceMap;
+use rustc_span::Span;
+
+use crate::emitter::Emitter;
+use crate::registry::Registry;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{DiagnosticId, FluentBundle, LazyFallbackBundle, Level, MultiSpan, SubDiagnostic};
+use rustc_lint_defs::Applicability;
+
+use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
+use rustc_error_messages::FluentArgs;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+pub struct SarifEmitter {
+    dst: IntoDynSyncSend<Box<dyn Write + Send>>,
+    registry: Option<Registry>,
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    fallback_bundle: LazyFallbackBundle,
+    tool_version: Option<&'static str>,
+    ui_testing: bool,
+    early: bool,
+    /// The rules of the results so far, by id.
+    rules: BTreeMap<String, ReportingDescriptor>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifEmitter {
+    pub fn stderr(
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        let dst = Box::new(io::BufWriter::new(io::stderr()));
+        SarifEmitter::new(dst, registry, source_map, fluent_bundle, fallback_bundle)
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst: IntoDynSyncSend(dst),
+            registry,
+            sm: source_map,
+            fluent_bundle,
+            fallback_bundle,
+            tool_version: None,
+            ui_testing: false,
+            early: false,
+            rules: BTreeMap::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Sets the version of the tool reported in the log.
+    pub fn tool_version(self, tool_version: Option<&'static str>) -> Self {
+        Self { tool_version, ..self }
+    }
+
+    pub fn ui_testing(self, ui_testing: bool) -> Self {
+        Self { ui_testing, ..self }
+    }
+
+    /// Makes this the emitter for diagnostics emitted before the session exists, which only
+    /// writes a log if there was an error. As such an error stops the compilation, its log is
+    /// then the only one. Warnings are dropped, as they would otherwise end up in a second log
+    /// next to the one of the session.
+    pub fn early(self, early: bool) -> Self {
+        Self { early, ..self }
+    }
+
+    fn rule(&mut self, id: &DiagnosticId) -> String {
+        let (id, help_uri, explanation) = match id {
+            DiagnosticId::Error(code) => {
+                let explanation = self
+                    .registry
+                    .as_ref()
+                    .and_then(|registry| registry.try_find_description(code).ok());
+                let help_uri = format!("https://doc.rust-lang.org/error_codes/{code}.html");
+                (code, Some(help_uri), explanation)
+            }
+            DiagnosticId::Lint { name, .. } => {
+                let help_uri = name.strip_prefix("clippy::").map(|name| {
+                    format!("https://rust-lang.github.io/rust-clippy/master/index.html#{name}")
+                });
+                (name, help_uri, None)
+            }
+        };
+        self.rules.entry(id.clone()).or_insert_with(|| ReportingDescriptor {
+            id: id.clone(),
+            full_description: explanation.map(|explanation| MultiformatMessageString {
+                text: explanation.to_string(),
+                markdown: explanation.to_string(),
+            }),
+            help_uri,
+        });
+        id.clone()
+    }
+
+    fn region(&self, span: Span) -> Region {
+        let start = self.sm.lookup_char_pos(span.lo());
+        let end = self.sm.lookup_char_pos(span.hi());
+        let byte_start = start.file.original_relative_byte_pos(span.lo()).0;
+        let byte_end = start.file.original_relative_byte_pos(span.hi()).0;
+        Region {
+            start_line: start.line,
+            start_column: start.col.0 + 1,
+            end_line: end.line,
+            end_column: end.col.0 + 1,
+            byte_offset: byte_start,
+            byte_length: byte_end - byte_start,
+        }
+    }
+
+    fn artifact_location(&self, span: Span) -> ArtifactLocation {
+        let file = self.sm.lookup_source_file(span.lo());
+        let path = self.sm.filename_for_diagnostics(&file.name).to_string();
+        ArtifactLocation { uri: file_uri(&path) }
+    }
+
+    fn location(&self, span: Span, message: Option<String>) -> Option<Location> {
+        if span.is_dummy() {
+            return None;
+        }
+        Some(Location {
+            physical_location: PhysicalLocation {
+                artifact_location: self.artifact_location(span),
+                region: self.region(span),
+            },
+            message: message.map(|text| Message { text }),
+        })
+    }
+
+    /// Returns the primary and the secondary locations of `span`, with the
+    /// labels as their messages.
+    fn locations(
+        &self,
+        span: &MultiSpan,
+        args: &FluentArgs<'_>,
+    ) -> (Vec<Location>, Vec<Location>) {
+        let mut primary = Vec::new();
+        let mut secondary = Vec::new();
+        for label in span.span_labels() {
+            let message = label
+                .label
+                .as_ref()
+                .map(|label| self.translate_message(label, args).unwrap().to_string());
+            let Some(location) = self.location(label.span, message) else { continue };
+            if label.is_primary {
+                primary.push(location);
+            } else {
+                secondary.push(location);
+            }
+        }
+        (primary, secondary)
+    }
+
+    fn child(
+        &self,
+        child: &SubDiagnostic,
+        args: &FluentArgs<'_>,
+        message: &mut String,
+        related_locations: &mut Vec<Location>,
+    ) {
+        let text = format!(
+            "{}: {}",
+            child.level.to_str(),
+            self.translate_messages(&child.message, args)
+        );
+        let span = child.render_span.as_ref().unwrap_or(&child.span);
+        let (primary, secondary) = self.locations(span, args);
+        if primary.is_empty() {
+            message.push('\n');
+            message.push_str(&text);
+        }
+        for mut location in primary {
+            location.message = Some(Message {
+                text: match location.message {
+                    Some(label) => format!("{text}: {}", label.text),
+                    None => text.clone(),
+                },
+            });
+            related_locations.push(location);
+        }
+        related_locations.extend(secondary);
+    }
+
+    fn write_log(&mut self) -> io::Result<()> {
+        let log = Log {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "rustc",
+                        version: if self.ui_testing { None } else { self.tool_version },
+                        information_uri: "https://www.rust-lang.org/",
+                        rules: self.rules.values().collect(),
+                    },
+                },
+                results: &self.results,
+                column_kind: "unicodeCodePoints",
+            }],
+        };
+        writeln!(&mut self.dst, "{}", serde_json::to_string_pretty(&log).unwrap())?;
+        self.dst.flush()
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        if self.early && self.results.is_empty() {
+            return;
+        }
+        if let Err(e) = self.write_log() {
+            panic!("failed to print diagnostics: {e:?}");
+        }
+    }
+}
+
+impl Translate for SarifEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: &crate::Diagnostic) {
+        let level = match diag.level {
+            Level::Bug | Level::DelayedBug | Level::Fatal | Level::Error { .. } => "error",
+            Level::Warning(_) => "warning",
+            Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp => "note",
+            Level::Allow | Level::Expect(_) => "none",
+            // These only point to `--explain` and the like.
+            Level::FailureNote => return,
+        };
+        if self.early && level != "error" {
+            return;
+        }
+        let args = to_fluent_args(diag.args());
+
+        let mut message = self.translate_messages(&diag.message, &args).into_owned();
+        let (locations, mut related_locations) = self.locations(&diag.span, &args);
+        for child in &diag.children {
+            self.child(child, &args, &mut message, &mut related_locations);
+        }
+
+        let this = &*self;
+        let fixes = diag
+            .suggestions
+            .iter()
+            .flatten()
+            .flat_map(|sugg| {
+                let description = this.translate_message(&sugg.msg, &args).unwrap().to_string();
+                sugg.substitutions.iter().map(move |substitution| {
+                    let mut artifact_changes: Vec<ArtifactChange> = Vec::new();
+                    for part in &substitution.parts {
+                        let artifact_location = this.artifact_location(part.span);
+                        let replacement = Replacement {
+                            deleted_region: this.region(part.span),
+                            inserted_content: ArtifactContent { text: part.snippet.clone() },
+                        };
+                        match artifact_changes
+                            .iter_mut()
+                            .find(|change| change.artifact_location == artifact_location)
+                        {
+                            Some(change) => change.replacements.push(replacement),
+                            None => artifact_changes.push(ArtifactChange {
+                                artifact_location,
+                                replacements: vec![replacement],
+                            }),
+                        }
+                    }
+                    Fix {
+                        description: Message { text: description.clone() },
+                        artifact_changes,
+                        properties: FixProperties { applicability: sugg.applicability },
+                    }
+                })
+            })
+            .collect();
+
+        let rule_id = diag.code.as_ref().map(|code| self.rule(code));
+        self.results.push(SarifResult {
+            rule_id,
+            level,
+            message: Message { text: message },
+            locations,
+            related_locations,
+            fixes,
+        });
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+
+    fn should_show_error_count(&self) -> bool {
+        false
+    }
+}
+
+/// Turns a path as printed in diagnostics into a URI reference.
+///
+/// Relative paths stay relative, as there is no meaningful base to resolve
+/// them against.
+fn file_uri(path: &str) -> String {
+    let path = if cfg!(windows) { path.replace('\\', "/") } else { path.to_string() };
+    let mut uri = String::with_capacity(path.len());
+    if path.starts_with('/') {
+        uri.push_str("file://");
+    } else if path.as_bytes().get(1) == Some(&b':') {
+        // A Windows path with a drive letter.
+        uri.push_str("file:///");
+    }
+    for c in path.chars() {
+        match c {
+            ' ' => uri.push_str("%20"),
+            '#' => uri.push_str("%23"),
+            '%' => uri.push_str("%25"),
+            '?' => uri.push_str("%3F"),
+            c => uri.push(c),
+        }
+    }
+    uri
+}
+
+// The following data types are provided just for serialisation. Their names
+// and fields follow the SARIF object model.
+
+#[derive(Serialize)]
+struct Log<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    results: &'a [SarifResult],
+    column_kind: &'static str,
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'static str>,
+    information_uri: &'static str,
+    rules: Vec<&'a ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_description: Option<MultiformatMessageString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+struct MultiformatMessageString {
+    text: String,
+    markdown: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    level: &'static str,
+    message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize, PartialEq)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, in characters.
+    start_column: usize,
+    end_line: usize,
+    /// The column after the end of the region.
+    end_column: usize,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+    properties: FixProperties,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Serialize)]
+struct ArtifactContent {
+    text: String,
+}
+
+/// SARIF has no notion of how safe a fix is, so this goes in the property bag.
+#[derive(Serialize)]
+struct FixProperties {
+    applicability: Applicability,
+}
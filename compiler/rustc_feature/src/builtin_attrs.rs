@@ -437,6 +437,10 @@ pub struct BuiltinAttribute {
         optimize, Normal, template!(List: "size|speed"), ErrorPreceding, optimize_attribute,
         experimental!(optimize),
     ),
+    gated!(
+        mir_inline, Normal, template!(List: "always|cost = N"), ErrorPreceding,
+        mir_inline_attribute, experimental!(mir_inline),
+    ),
 
     gated!(
         ffi_returns_twice, Normal, template!(Word), WarnFollowing, experimental!(ffi_returns_twice)
@@ -634,6 +638,16 @@ pub struct BuiltinAttribute {
     // Used by the `rustc::bad_opt_access` lint on fields
     // types (as well as any others in future).
     rustc_attr!(rustc_lint_opt_deny_field_access, Normal, template!(List: "message"), WarnFollowing, INTERNAL_UNSTABLE),
+    // Used by the `unsupported_on_target` and `expensive_on_target` lints to identify APIs that
+    // fail or are very expensive on a specific target.
+    rustc_attr!(
+        rustc_unsupported_on, Normal, template!(List: r#"target_os = "...", /*opt*/ reason = "...""#),
+        DuplicatesOk, INTERNAL_UNSTABLE
+    ),
+    rustc_attr!(
+        rustc_expensive_on, Normal, template!(List: r#"target_os = "...", /*opt*/ reason = "...""#),
+        DuplicatesOk, INTERNAL_UNSTABLE
+    ),
 
     // ==========================================================================
     // Internal attributes, Const related:
@@ -189,6 +189,9 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (internal, lang_items, "1.0.0", None, None),
     /// Allows `#[link(..., cfg(..))]`; perma-unstable per #37406
     (unstable, link_cfg, "1.14.0", None, None),
+    /// Allows `#[mir_inline(..)]` to give the MIR inliner a cost for a function, or to request
+    /// that it is always inlined.
+    (unstable, mir_inline_attribute, "CURRENT_RUSTC_VERSION", None, None),
     /// Allows the `multiple_supertrait_upcastable` lint.
     (unstable, multiple_supertrait_upcastable, "1.69.0", None, None),
     /// Allow negative trait bounds. This is an internal-only feature for testing the trait solver!
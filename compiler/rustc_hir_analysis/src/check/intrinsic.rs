@@ -111,6 +111,7 @@ pub fn intrinsic_operation_unsafety(tcx: TyCtxt<'_>, intrinsic_id: DefId) -> hir
         | sym::type_name
         | sym::forget
         | sym::black_box
+        | sym::value_barrier
         | sym::variant_count
         | sym::ptr_mask => hir::Unsafety::Normal,
         _ => hir::Unsafety::Unsafe,
@@ -474,6 +475,7 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
             }
 
             sym::black_box => (1, vec![param(0)], param(0)),
+            sym::value_barrier => (1, vec![param(0)], param(0)),
 
             sym::const_eval_select => (4, vec![param(0), param(1), param(2)], param(3)),
 
@@ -22,7 +22,7 @@
 use rustc_middle::util::Providers;
 use rustc_mir_build as mir_build;
 use rustc_parse::{parse_crate_from_file, parse_crate_from_source_str, validate_attr};
-use rustc_passes::{self, abi_test, hir_stats, layout_test};
+use rustc_passes::{self, abi_test, hir_stats, layout_snapshot, layout_test};
 use rustc_resolve::Resolver;
 use rustc_session::code_stats::VTableSizeInfo;
 use rustc_session::config::{CrateType, Input, OutFileName, OutputFilenames, OutputType};
@@ -787,6 +787,8 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
         return Err(reported);
     }
 
+    sess.time("layout_snapshot", || layout_snapshot::layout_snapshot(tcx));
+
     sess.time("misc_checking_3", || {
         parallel!(
             {
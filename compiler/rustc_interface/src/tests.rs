@@ -672,6 +672,7 @@ macro_rules! untracked {
     untracked!(dump_mono_stats, SwitchWithOptPath::Enabled(Some("mono-items-dir/".into())));
     untracked!(dump_mono_stats_format, DumpMonoStatsFormat::Json);
     untracked!(dylib_lto, true);
+    untracked!(emit_fixes, Some(PathBuf::from("fixes.patch")));
     untracked!(emit_stack_sizes, true);
     untracked!(future_incompat_test, true);
     untracked!(hir_stats, true);
@@ -684,6 +685,7 @@ macro_rules! untracked {
     untracked!(llvm_time_trace, true);
     untracked!(ls, vec!["all".to_owned()]);
     untracked!(macro_backtrace, true);
+    untracked!(max_section_size, vec![(".text".to_owned(), 2 << 20)]);
     untracked!(meta_stats, true);
     untracked!(mir_include_spans, true);
     untracked!(nll_facts, true);
@@ -698,6 +700,7 @@ macro_rules! untracked {
     untracked!(print_llvm_passes, true);
     untracked!(print_mono_items, Some(String::from("abc")));
     untracked!(print_type_sizes, true);
+    untracked!(print_unreachable_items, true);
     untracked!(proc_macro_backtrace, true);
     untracked!(proc_macro_execution_strategy, ProcMacroExecutionStrategy::CrossThread);
     untracked!(profile_closures, true);
@@ -752,6 +755,7 @@ macro_rules! tracked {
     tracked!(debug_info_for_profiling, true);
     tracked!(debug_macros, true);
     tracked!(dep_info_omit_d_target, true);
+    tracked!(deterministic_output, true);
     tracked!(dual_proc_macros, true);
     tracked!(dwarf_version, Some(5));
     tracked!(emit_thin_lto, false);
@@ -769,6 +773,8 @@ macro_rules! tracked {
     tracked!(inline_mir_threshold, Some(123));
     tracked!(instrument_mcount, true);
     tracked!(instrument_xray, Some(InstrumentXRay::default()));
+    tracked!(layout_snapshot, Some(PathBuf::from("layouts.txt")));
+    tracked!(layout_snapshot_check, true);
     tracked!(link_directives, false);
     tracked!(link_only, true);
     tracked!(llvm_plugins, vec![String::from("plugin_name")]);
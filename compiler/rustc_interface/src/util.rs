@@ -7,7 +7,9 @@
 use rustc_data_structures::sync;
 use rustc_parse::validate_attr;
 use rustc_session as session;
-use rustc_session::config::{self, Cfg, CrateType, OutFileName, OutputFilenames, OutputTypes};
+use rustc_session::config::{
+    self, Cfg, CrateType, OutFileName, OutputFilenames, OutputType, OutputTypes,
+};
 use rustc_session::filesearch::sysroot_candidates;
 use rustc_session::lint::{self, BuiltinLintDiagnostics, LintBuffer};
 use rustc_session::{filesearch, output, Session};
@@ -505,8 +507,13 @@ pub fn build_output_filenames(attrs: &[ast::Attribute], sess: &Session) -> Outpu
         }
 
         Some(ref out_file) => {
-            let unnamed_output_types =
-                sess.opts.output_types.values().filter(|a| a.is_none()).count();
+            // The link map is named after the linked output, so it can share `-o`.
+            let unnamed_output_types = sess
+                .opts
+                .output_types
+                .iter()
+                .filter(|&(ty, path)| path.is_none() && *ty != OutputType::LinkMap)
+                .count();
             let ofile = if unnamed_output_types > 1 {
                 sess.emit_warning(errors::MultipleOutputTypesAdaption);
                 None
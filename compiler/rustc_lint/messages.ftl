@@ -213,6 +213,8 @@ lint_expectation = this lint expectation is unfulfilled
     .note = the `unfulfilled_lint_expectations` lint can't be expected and will always produce this message
     .rationale = {$rationale}
 
+lint_expensive_on_target = `{$path}` is very expensive on `target_os = "{$target_os}"`
+
 lint_for_loops_over_fallibles =
     for loop over {$article} `{$ty}`. This is more readably written as an `if let` statement
     .suggestion = consider using `if let` to clear intent
@@ -494,6 +496,9 @@ lint_renamed_lint = lint `{$name}` has been renamed to `{$replace}`
 
 lint_requested_level = requested on the command line with `{$level} {$lint_name}`
 
+lint_soft_float_arithmetic = floating-point arithmetic is emulated in software on `target_os = "{$target_os}"`
+    .note = further floating-point operations in this function are not reported
+
 lint_span_use_eq_ctxt = use `.eq_ctxt()` instead of `.ctxt() == .ctxt()`
 
 lint_supertrait_as_deref_target = `{$t}` implements `Deref` with supertrait `{$target_principal}` as target
@@ -505,6 +510,8 @@ lint_suspicious_double_ref_clone =
 lint_suspicious_double_ref_deref =
     using `.deref()` on a double reference, which returns `{$ty}` instead of dereferencing the inner type
 
+lint_target_restriction_reason = {$reason}
+
 lint_trivial_untranslatable_diag = diagnostic with static strings only
 
 lint_ty_qualified = usage of qualified `ty::{$ty}`
@@ -537,6 +544,8 @@ lint_unknown_tool_in_scoped_lint = unknown tool name `{$tool_name}` found in sco
 
 lint_unsupported_group = `{$lint_group}` lint group is not supported with ´--force-warn´
 
+lint_unsupported_on_target = `{$path}` is not supported on `target_os = "{$target_os}"`
+
 lint_untranslatable_diag = diagnostics should be created using translatable messages
 
 lint_unused_allocation = unnecessary allocation, use `&` instead
@@ -572,5 +581,9 @@ lint_unused_op = unused {$op} that must be used
 
 lint_unused_result = unused result of type `{$ty}`
 
+lint_unused_write_count = the number of bytes written by `write` is ignored
+    .note = `write` may write only a part of the buffer, and the rest of it is then lost
+    .suggestion = use `write_all` to write the whole buffer
+
 lint_variant_size_differences =
     enum variant is more than three times larger ({$largest} bytes) than the next largest
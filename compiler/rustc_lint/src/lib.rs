@@ -83,9 +83,11 @@
 mod ptr_nulls;
 mod redundant_semicolon;
 mod reference_casting;
+mod target_specific;
 mod traits;
 mod types;
 mod unused;
+mod unused_write_count;
 
 pub use array_into_iter::ARRAY_INTO_ITER;
 
@@ -121,9 +123,11 @@
 use ptr_nulls::*;
 use redundant_semicolon::*;
 use reference_casting::*;
+use target_specific::*;
 use traits::*;
 use types::*;
 use unused::*;
+use unused_write_count::*;
 
 /// Useful for other parts of the compiler / Clippy.
 pub use builtin::{MissingDoc, SoftLints};
@@ -196,6 +200,7 @@ fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalModDefId) {
             ImproperCTypesDeclarations: ImproperCTypesDeclarations,
             ImproperCTypesDefinitions: ImproperCTypesDefinitions,
             InvalidFromUtf8: InvalidFromUtf8,
+            UnusedWriteCount: UnusedWriteCount,
             VariantSizeDifferences: VariantSizeDifferences,
             BoxPointers: BoxPointers,
             PathStatements: PathStatements,
@@ -239,6 +244,8 @@ fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalModDefId) {
             MissingDebugImplementations: MissingDebugImplementations,
             MissingDoc: MissingDoc,
             AsyncFnInTrait: AsyncFnInTrait,
+            TargetRestrictedApis: TargetRestrictedApis,
+            SoftFloatArithmetic: SoftFloatArithmetic::default(),
         ]
     ]
 );
@@ -304,6 +311,13 @@ macro_rules! add_lint_group {
 
     add_lint_group!("let_underscore", LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK);
 
+    add_lint_group!(
+        "target_hostile_code",
+        UNSUPPORTED_ON_TARGET,
+        EXPENSIVE_ON_TARGET,
+        SOFT_FLOAT_ARITHMETIC
+    );
+
     add_lint_group!(
         "rust_2018_idioms",
         BARE_TRAIT_OBJECTS,
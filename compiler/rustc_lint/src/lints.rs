@@ -1401,6 +1401,38 @@ fn msg(&self) -> rustc_errors::DiagnosticMessage {
     }
 }
 
+// target_specific.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_unsupported_on_target)]
+pub struct UnsupportedOnTargetDiag {
+    pub path: String,
+    pub target_os: Symbol,
+    #[subdiagnostic]
+    pub reason: Option<TargetRestrictionReason>,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(lint_expensive_on_target)]
+pub struct ExpensiveOnTargetDiag {
+    pub path: String,
+    pub target_os: Symbol,
+    #[subdiagnostic]
+    pub reason: Option<TargetRestrictionReason>,
+}
+
+#[derive(Subdiagnostic)]
+#[note(lint_target_restriction_reason)]
+pub struct TargetRestrictionReason {
+    pub reason: Symbol,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(lint_soft_float_arithmetic)]
+#[note]
+pub struct SoftFloatArithmeticDiag {
+    pub target_os: Symbol,
+}
+
 // types.rs
 #[derive(LintDiagnostic)]
 #[diag(lint_range_endpoint_out_of_range)]
@@ -1825,6 +1857,15 @@ pub struct UnusedImportBracesDiag {
 #[diag(lint_unused_allocation_mut)]
 pub struct UnusedAllocationMutDiag;
 
+// unused_write_count.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_unused_write_count)]
+#[note]
+pub struct UnusedWriteCountDiag {
+    #[suggestion(code = "write_all", applicability = "machine-applicable")]
+    pub suggestion: Span,
+}
+
 pub struct AsyncFnInTraitDiag {
     pub sugg: Option<Vec<(Span, String)>>,
 }
@@ -0,0 +1,221 @@
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{self as hir, BinOpKind, Expr, ExprKind};
+use rustc_session::{declare_lint, declare_lint_pass, impl_lint_pass};
+use rustc_span::symbol::{sym, Symbol};
+
+use crate::lints::{
+    ExpensiveOnTargetDiag, SoftFloatArithmeticDiag, TargetRestrictionReason,
+    UnsupportedOnTargetDiag,
+};
+use crate::{LateContext, LateLintPass, LintContext};
+
+declare_lint! {
+    /// The `unsupported_on_target` lint detects uses of APIs that are known to
+    /// always fail or panic on the target being compiled for.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a target where `std::fs` is unsupported)
+    /// #![warn(unsupported_on_target)]
+    ///
+    /// fn main() {
+    ///     let _ = std::fs::read("input.bin");
+    /// }
+    /// ```
+    ///
+    /// This will produce, when compiling for `riscv32im-succinct-zkvm-elf`:
+    ///
+    /// ```text
+    /// warning: `std::fs::read` is not supported on `target_os = "zkvm"`
+    ///  --> src/main.rs:4:13
+    ///   |
+    /// 4 |     let _ = std::fs::read("input.bin");
+    ///   |             ^^^^^^^^^^^^^
+    ///   |
+    ///   = note: there is no file system inside the zkVM
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// The standard library is available on many targets that only implement
+    /// a subset of its functionality. Calls to the remaining APIs compile
+    /// fine, but return an `Unsupported` error or panic at runtime. The
+    /// standard library marks such APIs with `#[rustc_unsupported_on]`, so
+    /// that they are reported at compile time instead.
+    ///
+    /// This lint is "allow" by default, except when compiling for
+    /// `target_os = "zkvm"`, where finding out at runtime means finding out
+    /// when the program is proven.
+    pub UNSUPPORTED_ON_TARGET,
+    Allow,
+    "use of an API that is not supported on the target",
+    @target_os "zkvm" => Warn;
+}
+
+declare_lint! {
+    /// The `expensive_on_target` lint detects uses of APIs that work, but are
+    /// known to be very expensive on the target being compiled for.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a target where `HashMap::new` is expensive)
+    /// #![warn(expensive_on_target)]
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() {
+    ///     let map: HashMap<u32, u32> = HashMap::new();
+    /// }
+    /// ```
+    ///
+    /// This will produce, when compiling for `riscv32im-succinct-zkvm-elf`:
+    ///
+    /// ```text
+    /// warning: `std::collections::HashMap::<K, V>::new` is very expensive on `target_os = "zkvm"`
+    ///  --> src/main.rs:5:34
+    ///   |
+    /// 5 |     let map: HashMap<u32, u32> = HashMap::new();
+    ///   |                                  ^^^^^^^^^^^^
+    ///   |
+    ///   = note: every `RandomState` requests new keys from the host
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// Some APIs, such as those relying on randomness or on hardware support
+    /// that the target lacks, are much more expensive on some targets than on
+    /// common hosts. The standard library marks such APIs with
+    /// `#[rustc_expensive_on]`.
+    ///
+    /// This lint is "allow" by default, except when compiling for
+    /// `target_os = "zkvm"`, where every executed instruction has to be proven.
+    pub EXPENSIVE_ON_TARGET,
+    Allow,
+    "use of an API that is very expensive on the target",
+    @target_os "zkvm" => Warn;
+}
+
+declare_lint_pass!(TargetRestrictedApis => [UNSUPPORTED_ON_TARGET, EXPENSIVE_ON_TARGET]);
+
+impl<'tcx> LateLintPass<'tcx> for TargetRestrictedApis {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let def_id = match expr.kind {
+            ExprKind::Path(ref qpath) => cx.qpath_res(qpath, expr.hir_id).opt_def_id(),
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            _ => None,
+        };
+        // The markers are meant for the users of an API, not for the crate defining it.
+        let Some(def_id) = def_id.filter(|def_id| !def_id.is_local()) else { return };
+        let span = match expr.kind {
+            ExprKind::MethodCall(segment, ..) => segment.ident.span,
+            _ => expr.span,
+        };
+
+        let target_os = Symbol::intern(&cx.tcx.sess.target.os);
+        if let Some(restriction) = cx.tcx.target_restriction(def_id, sym::rustc_unsupported_on) {
+            // Final artifacts for such targets report these uses as errors during
+            // monomorphization instead.
+            if cx.tcx.denies_unsupported_apis() {
+                return;
+            }
+            cx.emit_spanned_lint(
+                UNSUPPORTED_ON_TARGET,
+                span,
+                UnsupportedOnTargetDiag {
+                    path: cx.tcx.def_path_str(def_id),
+                    target_os,
+                    reason: restriction.reason.map(|reason| TargetRestrictionReason { reason }),
+                },
+            );
+        } else if let Some(restriction) = cx.tcx.target_restriction(def_id, sym::rustc_expensive_on)
+        {
+            cx.emit_spanned_lint(
+                EXPENSIVE_ON_TARGET,
+                span,
+                ExpensiveOnTargetDiag {
+                    path: cx.tcx.def_path_str(def_id),
+                    target_os,
+                    reason: restriction.reason.map(|reason| TargetRestrictionReason { reason }),
+                },
+            );
+        }
+    }
+}
+
+declare_lint! {
+    /// The `soft_float_arithmetic` lint detects floating-point arithmetic in
+    /// functions compiled for targets where it is emulated in software.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a target without hardware floating-point support)
+    /// #![warn(soft_float_arithmetic)]
+    ///
+    /// fn mean(values: &[f64]) -> f64 {
+    ///     values.iter().sum::<f64>() / values.len() as f64
+    /// }
+    /// ```
+    ///
+    /// This will produce, when compiling for `riscv32im-succinct-zkvm-elf`:
+    ///
+    /// ```text
+    /// warning: floating-point arithmetic is emulated in software on `target_os = "zkvm"`
+    ///  --> src/lib.rs:4:5
+    ///   |
+    /// 4 |     values.iter().sum::<f64>() / values.len() as f64
+    ///   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: further floating-point operations in this function are not reported
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// On targets without a floating-point unit, each floating-point
+    /// operation is lowered to a call into `compiler-builtins`, which costs
+    /// tens to hundreds of integer instructions. Fixed-point or integer
+    /// arithmetic is usually much cheaper there. The lint is only reported
+    /// once per function.
+    ///
+    /// This lint is "allow" by default, except when compiling for
+    /// `target_os = "zkvm"`, whose RV32IM instruction set has no
+    /// floating-point extension.
+    pub SOFT_FLOAT_ARITHMETIC,
+    Allow,
+    "floating-point arithmetic on a target where it is emulated in software",
+    @target_os "zkvm" => Warn;
+}
+
+#[derive(Default)]
+pub struct SoftFloatArithmetic {
+    /// Bodies in which the lint was already emitted.
+    reported: FxHashSet<hir::BodyId>,
+}
+
+impl_lint_pass!(SoftFloatArithmetic => [SOFT_FLOAT_ARITHMETIC]);
+
+impl<'tcx> LateLintPass<'tcx> for SoftFloatArithmetic {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (ExprKind::Binary(op, lhs, _) | ExprKind::AssignOp(op, lhs, _)) = expr.kind else {
+            return;
+        };
+        if !matches!(
+            op.node,
+            BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul | BinOpKind::Div | BinOpKind::Rem
+        ) || !cx.typeck_results().expr_ty(lhs).is_floating_point()
+        {
+            return;
+        }
+
+        let Some(body_id) = cx.enclosing_body else { return };
+        // Constants are evaluated at compile time, so they cost nothing at runtime.
+        let owner = cx.tcx.hir().body_owner_def_id(body_id);
+        if cx.tcx.hir().body_const_context(owner).is_some() || !self.reported.insert(body_id) {
+            return;
+        }
+
+        cx.emit_spanned_lint(
+            SOFT_FLOAT_ARITHMETIC,
+            expr.span,
+            SoftFloatArithmeticDiag { target_os: Symbol::intern(&cx.tcx.sess.target.os) },
+        );
+    }
+}
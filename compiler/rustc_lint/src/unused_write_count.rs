@@ -0,0 +1,108 @@
+use rustc_hir::{self as hir, Expr, ExprKind, MatchSource, PatKind, QPath, Stmt, StmtKind};
+use rustc_span::{sym, Span};
+
+use crate::lints::UnusedWriteCountDiag;
+use crate::{LateContext, LateLintPass, LintContext};
+
+declare_lint! {
+    /// The `unused_write_count` lint detects calls to `io::Write::write` whose
+    /// result is thrown away without looking at the number of bytes written.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(unused_write_count)]
+    /// use std::io::Write;
+    ///
+    /// fn commit(out: &mut impl Write, data: &[u8]) -> std::io::Result<()> {
+    ///     out.write(data)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// `write` is allowed to write only a part of the buffer, and returns how
+    /// many bytes it wrote. Code that ignores that number silently loses the
+    /// rest of the data whenever a writer does a short write, even if the
+    /// writers it was tested with never do. `write_all` retries until the whole
+    /// buffer is written, which is what is intended in almost all such cases.
+    ///
+    /// This lint is "allow" by default because short writes are rare for many
+    /// writers. It is useful for code whose output must be complete, such as
+    /// the public output committed by programs running in a zkVM.
+    pub UNUSED_WRITE_COUNT,
+    Allow,
+    "detects calls to `io::Write::write` where the number of bytes written is ignored"
+}
+
+declare_lint_pass!(UnusedWriteCount => [UNUSED_WRITE_COUNT]);
+
+impl<'tcx> LateLintPass<'tcx> for UnusedWriteCount {
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) {
+        if let StmtKind::Semi(expr) = stmt.kind {
+            check_discarded(cx, expr);
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx hir::Local<'tcx>) {
+        if let PatKind::Wild = local.pat.kind
+            && let Some(init) = local.init
+        {
+            check_discarded(cx, init);
+        }
+    }
+}
+
+/// Checks an expression whose value is thrown away. The result of `write` is
+/// looked for through `?`, `unwrap` and `expect`, which only get rid of the
+/// error and leave the count.
+fn check_discarded<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) {
+    loop {
+        expr = match expr.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) => {
+                // `x?` is `match Try::branch(x) { .. }`.
+                let ExprKind::Call(_, [inner]) = scrutinee.kind else { return };
+                inner
+            }
+            ExprKind::MethodCall(segment, receiver, ..)
+                if matches!(segment.ident.name, sym::unwrap | sym::expect) =>
+            {
+                receiver
+            }
+            _ => break,
+        };
+    }
+
+    if let Some(name) = io_write_call(cx, expr)
+        && !name.from_expansion()
+    {
+        cx.emit_spanned_lint(UNUSED_WRITE_COUNT, name, UnusedWriteCountDiag { suggestion: name });
+    }
+}
+
+/// If `expr` calls `io::Write::write`, returns the span of the name `write` in
+/// it.
+fn io_write_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Span> {
+    let (def_id, name) = match expr.kind {
+        ExprKind::MethodCall(segment, ..) => {
+            (cx.typeck_results().type_dependent_def_id(expr.hir_id)?, segment.ident)
+        }
+        // `Write::write(&mut w, buf)`
+        ExprKind::Call(path, _) => {
+            let ExprKind::Path(ref qpath) = path.kind else { return None };
+            let name = match qpath {
+                QPath::Resolved(_, path) => path.segments.last()?.ident,
+                QPath::TypeRelative(_, segment) => segment.ident,
+                QPath::LangItem(..) => return None,
+            };
+            (cx.qpath_res(qpath, path.hir_id).opt_def_id()?, name)
+        }
+        _ => return None,
+    };
+    let trait_id = cx.tcx.trait_of_item(def_id)?;
+    (cx.tcx.is_diagnostic_item(sym::IoWrite, trait_id) && cx.tcx.item_name(def_id) == sym::write)
+        .then_some(name.span)
+}
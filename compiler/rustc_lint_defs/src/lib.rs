@@ -316,6 +316,10 @@ pub struct Lint {
     /// `default_level`.
     pub edition_lint_opts: Option<(Edition, Level)>,
 
+    /// When compiling for a target with the given `target_os`, default to the given lint level.
+    /// This takes precedence over `edition_lint_opts`.
+    pub target_os_lint_opts: Option<(&'static str, Level)>,
+
     /// `true` if this lint is reported even inside expansions of external macros.
     pub report_in_external_macro: bool,
 
@@ -399,6 +403,7 @@ pub const fn default_fields_for_macro() -> Self {
             default_level: Level::Forbid,
             desc: "",
             edition_lint_opts: None,
+            target_os_lint_opts: None,
             is_loaded: false,
             report_in_external_macro: false,
             future_incompatible: None,
@@ -412,10 +417,11 @@ pub fn name_lower(&self) -> String {
         self.name.to_ascii_lowercase()
     }
 
-    pub fn default_level(&self, edition: Edition) -> Level {
-        self.edition_lint_opts
-            .filter(|(e, _)| *e <= edition)
+    pub fn default_level(&self, edition: Edition, target_os: &str) -> Level {
+        self.target_os_lint_opts
+            .filter(|(os, _)| *os == target_os)
             .map(|(_, l)| l)
+            .or_else(|| self.edition_lint_opts.filter(|(e, _)| *e <= edition).map(|(_, l)| l))
             .unwrap_or(self.default_level)
     }
 }
@@ -729,6 +735,7 @@ macro_rules! declare_lint {
         $($field:ident : $val:expr),* $(,)*
      }; )?
      $(@edition $lint_edition:ident => $edition_level:ident;)?
+     $(@target_os $lint_target_os:literal => $target_os_level:ident;)?
      $($v:ident),*) => (
         $(#[$attr])*
         $vis static $NAME: &$crate::Lint = &$crate::Lint {
@@ -744,6 +751,7 @@ macro_rules! declare_lint {
                 ..$crate::FutureIncompatibleInfo::default_fields_for_macro()
             }),)?
             $(edition_lint_opts: Some(($crate::Edition::$lint_edition, $crate::$edition_level)),)?
+            $(target_os_lint_opts: Some(($lint_target_os, $crate::$target_os_level)),)?
             ..$crate::Lint::default_fields_for_macro()
         };
     );
@@ -30,6 +30,7 @@
 #include "llvm/Target/TargetMachine.h"
 #include "llvm/Transforms/IPO/AlwaysInliner.h"
 #include "llvm/Transforms/IPO/FunctionImport.h"
+#include "llvm/Transforms/IPO/GlobalDCE.h"
 #include "llvm/Transforms/IPO/Internalize.h"
 #include "llvm/Transforms/IPO/LowerTypeTests.h"
 #include "llvm/Transforms/IPO/ThinLTOBitcodeWriter.h"
@@ -47,6 +48,7 @@
 #include "llvm/Transforms/Instrumentation/MemorySanitizer.h"
 #include "llvm/Transforms/Instrumentation/HWAddressSanitizer.h"
 #include "llvm/Transforms/Utils/CanonicalizeAliases.h"
+#include "llvm/Transforms/Utils/Cloning.h"
 #include "llvm/Transforms/Utils/NameAnonGlobals.h"
 #include "llvm/Transforms/Utils.h"
 
@@ -1146,6 +1148,29 @@ extern "C" void LLVMRustRunRestrictionPass(LLVMModuleRef M, char **Symbols,
   internalizeModule(*unwrap(M), PreserveFunctions);
 }
 
+// Writes the names of the functions defined in `M` that global dead code
+// elimination would remove, one per line. `M` itself is left unchanged, the
+// pass is run on a copy of it.
+extern "C" void LLVMRustWriteUnreachableFunctions(LLVMModuleRef M,
+                                                  RustStringRef Str) {
+  Module &Mod = *unwrap(M);
+  std::unique_ptr<Module> Clone = CloneModule(Mod);
+
+  ModuleAnalysisManager MAM;
+  PassBuilder PB;
+  PB.registerModuleAnalyses(MAM);
+  ModulePassManager MPM;
+  MPM.addPass(GlobalDCEPass());
+  MPM.run(*Clone, MAM);
+
+  RawRustStringOstream OS(Str);
+  for (const Function &F : Mod) {
+    if (!F.isDeclaration() && !Clone->getFunction(F.getName())) {
+      OS << F.getName() << "\n";
+    }
+  }
+}
+
 extern "C" void
 LLVMRustSetDataLayoutFromTargetMachine(LLVMModuleRef Module,
                                        LLVMTargetMachineRef TMR) {
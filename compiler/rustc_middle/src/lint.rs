@@ -76,7 +76,8 @@ pub fn reveal_actual_level(
     probe_for_lint_level: impl FnOnce(LintId) -> (Option<Level>, LintLevelSource),
 ) -> Level {
     // If `level` is none then we actually assume the default level for this lint.
-    let mut level = level.unwrap_or_else(|| lint.lint.default_level(sess.edition()));
+    let mut level =
+        level.unwrap_or_else(|| lint.lint.default_level(sess.edition(), &sess.target.os));
 
     // If we're about to issue a warning, check at the last minute for any
     // directives against the warnings "lint". If, for example, there's an
@@ -1,5 +1,5 @@
 use crate::mir::mono::Linkage;
-use rustc_attr::{InlineAttr, InstructionSetAttr, OptimizeAttr};
+use rustc_attr::{InlineAttr, InstructionSetAttr, MirInlineAttr, OptimizeAttr};
 use rustc_span::symbol::Symbol;
 use rustc_target::spec::SanitizerSet;
 
@@ -8,6 +8,8 @@ pub struct CodegenFnAttrs {
     pub flags: CodegenFnAttrFlags,
     /// Parsed representation of the `#[inline]` attribute
     pub inline: InlineAttr,
+    /// Parsed representation of the `#[mir_inline]` attribute
+    pub mir_inline: MirInlineAttr,
     /// Parsed representation of the `#[optimize]` attribute
     pub optimize: OptimizeAttr,
     /// The `#[export_name = "..."]` attribute, indicating a custom symbol a
@@ -112,6 +114,7 @@ pub const fn new() -> CodegenFnAttrs {
         CodegenFnAttrs {
             flags: CodegenFnAttrFlags::empty(),
             inline: InlineAttr::None,
+            mir_inline: MirInlineAttr::None,
             optimize: OptimizeAttr::None,
             export_name: None,
             link_name: None,
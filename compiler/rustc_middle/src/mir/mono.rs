@@ -3,7 +3,7 @@
 use rustc_attr::InlineAttr;
 use rustc_data_structures::base_n;
 use rustc_data_structures::fingerprint::Fingerprint;
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
 use rustc_data_structures::stable_hasher::{Hash128, HashStable, StableHasher};
 use rustc_hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use rustc_hir::ItemId;
@@ -454,6 +454,27 @@ fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHas
     }
 }
 
+/// The call graph between the functions codegened in the local crate, keyed by
+/// symbol name so that it can outlive the `TyCtxt` and be matched against the
+/// symbols of the linked output. Used by `--print stack-usage`.
+#[derive(Debug, Default, Clone, Encodable, Decodable)]
+pub struct MonoCallGraph {
+    /// The functions the stack usage analysis starts from: the entry function
+    /// and every function exported under an unmangled name.
+    pub roots: Vec<String>,
+    /// Every function codegened in the local crate, sorted by symbol name.
+    pub nodes: FxIndexMap<String, MonoCallGraphNode>,
+}
+
+#[derive(Debug, Default, Clone, Encodable, Decodable)]
+pub struct MonoCallGraphNode {
+    /// Symbol names of the functions that are called directly, without duplicates.
+    pub callees: Vec<String>,
+    /// Whether the function calls through a function pointer or a `dyn` vtable,
+    /// in which case the set of callees is not statically known.
+    pub has_indirect_calls: bool,
+}
+
 pub struct CodegenUnitNameBuilder<'tcx> {
     tcx: TyCtxt<'tcx>,
     cache: FxHashMap<CrateNum, String>,
@@ -23,7 +23,7 @@
     EvalToAllocationRawResult, EvalToConstValueResult, EvalToValTreeResult,
 };
 use crate::mir::interpret::{LitToConstError, LitToConstInput};
-use crate::mir::mono::CodegenUnit;
+use crate::mir::mono::{CodegenUnit, MonoCallGraph};
 use crate::query::erase::{erase, restore, Erase};
 use crate::query::plumbing::{
     query_ensure, query_ensure_error_guaranteed, query_get_at, CyclePlaceholder, DynamicQuery,
@@ -1887,6 +1887,15 @@
         desc { "getting codegen unit `{sym}`" }
     }
 
+    /// The direct call graph between all functions codegened in the local crate,
+    /// used by `--print stack-usage`.
+    query mono_call_graph(_: ()) -> &'tcx MonoCallGraph {
+        arena_cache
+        eval_always
+        no_hash
+        desc { "building the call graph of monomorphized functions" }
+    }
+
     query unused_generic_params(key: ty::InstanceDef<'tcx>) -> UnusedGenericParams {
         cache_on_disk_if { key.def_id().is_local() }
         desc {
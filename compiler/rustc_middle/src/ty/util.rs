@@ -18,7 +18,7 @@
 use rustc_index::bit_set::GrowableBitSet;
 use rustc_macros::HashStable;
 use rustc_session::Limit;
-use rustc_span::sym;
+use rustc_span::{sym, Symbol};
 use rustc_target::abi::{Integer, IntegerType, Primitive, Size};
 use rustc_target::spec::abi::Abi;
 use smallvec::SmallVec;
@@ -792,6 +792,39 @@ pub fn is_user_visible_dep(self, key: CrateNum) -> bool {
             // the language.
             || self.extern_crate(key.as_def_id()).map_or(false, |e| e.is_direct())
     }
+
+    /// Whether referencing an API marked with `#[rustc_unsupported_on]` for the current target
+    /// is an error, which is the case for final artifacts built for targets that set
+    /// `deny_unsupported_apis`. These errors come from monomorphization, so this is only the case
+    /// if the crate is going to be codegened: `cargo check` still gets the lint.
+    pub fn denies_unsupported_apis(self) -> bool {
+        self.sess.target.deny_unsupported_apis
+            && self.sess.opts.output_types.should_codegen()
+            && self.crate_types().iter().any(|crate_type| !crate_type.has_metadata())
+    }
+
+    /// Looks for a `#[rustc_unsupported_on]` or `#[rustc_expensive_on]` attribute (as given by
+    /// `attr_name`) that applies to the current target on `def_id` or any of its parents, so that
+    /// a whole module or `impl` block can be marked at once.
+    pub fn target_restriction(
+        self,
+        def_id: DefId,
+        attr_name: Symbol,
+    ) -> Option<rustc_attr::TargetRestriction> {
+        let target_os = Symbol::intern(&self.sess.target.os);
+        let mut def_id = Some(def_id);
+        while let Some(id) = def_id {
+            let restriction = self
+                .get_attrs(id, attr_name)
+                .filter_map(rustc_attr::parse_target_restriction)
+                .find(|restriction| restriction.target_os == target_os);
+            if restriction.is_some() {
+                return restriction;
+            }
+            def_id = self.opt_parent(id);
+        }
+        None
+    }
 }
 
 struct OpaqueTypeExpander<'tcx> {
@@ -1,6 +1,6 @@
 use crate::inline;
 use crate::pass_manager as pm;
-use rustc_attr::InlineAttr;
+use rustc_attr::{InlineAttr, MirInlineAttr};
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::LocalDefId;
 use rustc_middle::mir::visit::Visitor;
@@ -30,6 +30,11 @@ fn cross_crate_inlinable(tcx: TyCtxt<'_>, def_id: LocalDefId) -> bool {
         _ => {}
     }
 
+    // A `#[mir_inline]` cost or request is only useful if other crates can inline the function.
+    if codegen_fn_attrs.mir_inline != MirInlineAttr::None {
+        return true;
+    }
+
     // This just reproduces the logic from Instance::requires_inline.
     match tcx.def_kind(def_id) {
         DefKind::Ctor(..) | DefKind::Closure => return true,
@@ -1,6 +1,6 @@
 //! Inlining pass for MIR functions
 use crate::deref_separator::deref_finder;
-use rustc_attr::InlineAttr;
+use rustc_attr::{InlineAttr, MirInlineAttr};
 use rustc_const_eval::transform::validate::validate_types;
 use rustc_hir::def_id::DefId;
 use rustc_index::bit_set::BitSet;
@@ -524,7 +524,17 @@ fn check_mir_body(
         // That attribute is often applied to very large functions that exceed LLVM's (very
         // generous) inlining threshold. Such functions are very poor MIR inlining candidates.
         // Always inlining #[inline(always)] functions in MIR, on net, slows down the compiler.
-        let cost = checker.cost();
+        //
+        // `#[mir_inline]` overrides the computed cost, for functions whose real cost the
+        // checker's constants don't capture.
+        let cost = match callee_attrs.mir_inline {
+            MirInlineAttr::None => checker.cost(),
+            MirInlineAttr::Cost(cost) => cost,
+            MirInlineAttr::Always => {
+                debug!("INLINING {:?} [mir_inline(always)]", callsite);
+                return Ok(());
+            }
+        };
         if cost <= threshold {
             debug!("INLINING {:?} [cost={} <= threshold={}]", callsite, cost, threshold);
             Ok(())
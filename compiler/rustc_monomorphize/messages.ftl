@@ -27,6 +27,14 @@ monomorphize_type_length_limit = reached the type-length limit while instantiati
 monomorphize_unknown_cgu_collection_mode =
     unknown codegen-item collection mode '{$mode}', falling back to 'lazy' mode
 
+monomorphize_unsupported_api_call_path = reached through {$call_path}
+
+monomorphize_unsupported_api_reason = {$reason}
+
+monomorphize_unsupported_api_use = `{$path}` is not supported on `target_os = "{$target_os}"`
+
+monomorphize_unsupported_api_user = used by `{$user}` from crate `{$user_crate}`
+
 monomorphize_unused_generic_params = item has unused generic parameters
 
 monomorphize_written_to_path = the full type name has been written to '{$path}'
@@ -0,0 +1,129 @@
+//! Builds the call graph between the monomorphized functions of the local crate.
+//!
+//! The graph is keyed by symbol name rather than by `Instance`, as its only
+//! consumer is the stack usage analysis behind `--print stack-usage`, which
+//! runs after linking and matches the graph against the per-function frame
+//! sizes that LLVM records in the `.stack_sizes` section of the output.
+//!
+//! Only the MIR of each function is inspected, so the graph is an
+//! over-approximation of what LLVM ends up emitting: calls that are inlined
+//! still appear as edges, and their callees' frames are then simply reported
+//! with a size of zero by the analysis.
+
+use rustc_data_structures::fx::{FxIndexMap, FxIndexSet};
+use rustc_hir::lang_items::LangItem;
+use rustc_middle::mir::mono::{MonoCallGraph, MonoCallGraphNode, MonoItem};
+use rustc_middle::mir::{self, AssertKind};
+use rustc_middle::query::Providers;
+use rustc_middle::ty::{self, Instance, InstanceDef, Ty, TyCtxt};
+
+fn mono_call_graph(tcx: TyCtxt<'_>, (): ()) -> MonoCallGraph {
+    let (_, codegen_units) = tcx.collect_and_partition_mono_items(());
+
+    let entry_fn = tcx.entry_fn(()).map(|(def_id, _)| def_id);
+
+    let mut roots = FxIndexSet::default();
+    let mut nodes = FxIndexMap::default();
+    for cgu in codegen_units {
+        for mono_item in cgu.items().keys() {
+            let MonoItem::Fn(instance) = *mono_item else { continue };
+            let symbol = tcx.symbol_name(instance).name.to_string();
+            if nodes.contains_key(&symbol) {
+                // Items with a local copy in several codegen units share a symbol name.
+                continue;
+            }
+
+            if let InstanceDef::Item(def_id) = instance.def {
+                if Some(def_id) == entry_fn
+                    || tcx.codegen_fn_attrs(def_id).contains_extern_indicator()
+                {
+                    roots.insert(symbol.clone());
+                }
+            }
+
+            nodes.insert(symbol, call_graph_node(tcx, instance));
+        }
+    }
+    nodes.sort_keys();
+
+    let mut roots: Vec<_> = roots.into_iter().collect();
+    roots.sort();
+
+    MonoCallGraph { roots, nodes }
+}
+
+fn call_graph_node<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> MonoCallGraphNode {
+    let body = tcx.instance_mir(instance.def);
+    let monomorphize = |ty: Ty<'tcx>| {
+        instance.instantiate_mir_and_normalize_erasing_regions(
+            tcx,
+            ty::ParamEnv::reveal_all(),
+            ty::EarlyBinder::bind(ty),
+        )
+    };
+
+    let mut callees = FxIndexSet::default();
+    let mut has_indirect_calls = false;
+    let mut push_callee = |callee: Instance<'tcx>| {
+        callees.insert(tcx.symbol_name(callee.polymorphize(tcx)).name.to_string());
+    };
+    let mono_lang_item = |lang_item: LangItem| {
+        Instance::mono(tcx, tcx.require_lang_item(lang_item, Some(body.span)))
+    };
+
+    for block in body.basic_blocks.iter() {
+        let terminator = block.terminator();
+        match terminator.kind {
+            mir::TerminatorKind::Call { ref func, .. } => {
+                let callee_ty = monomorphize(func.ty(body, tcx));
+                match *callee_ty.kind() {
+                    ty::FnDef(def_id, args) => {
+                        let callee =
+                            Instance::expect_resolve(tcx, ty::ParamEnv::reveal_all(), def_id, args);
+                        match callee.def {
+                            InstanceDef::Virtual(..) => has_indirect_calls = true,
+                            // Intrinsics are lowered in place by the backend.
+                            InstanceDef::Intrinsic(_) => {}
+                            _ => push_callee(callee),
+                        }
+                    }
+                    ty::FnPtr(_) => has_indirect_calls = true,
+                    _ => {}
+                }
+            }
+            mir::TerminatorKind::Drop { ref place, .. } => {
+                let ty = monomorphize(place.ty(body, tcx).ty);
+                if ty.is_trait() {
+                    // Dropping a trait object goes through its vtable.
+                    has_indirect_calls = true;
+                } else {
+                    let drop_glue = Instance::resolve_drop_in_place(tcx, ty);
+                    if !matches!(drop_glue.def, InstanceDef::DropGlue(_, None)) {
+                        push_callee(drop_glue);
+                    }
+                }
+            }
+            mir::TerminatorKind::Assert { ref msg, .. } => {
+                let lang_item = match &**msg {
+                    AssertKind::BoundsCheck { .. } => LangItem::PanicBoundsCheck,
+                    _ => LangItem::Panic,
+                };
+                push_callee(mono_lang_item(lang_item));
+            }
+            mir::TerminatorKind::UnwindTerminate(reason) => {
+                push_callee(mono_lang_item(reason.lang_item()));
+            }
+            _ => {}
+        }
+
+        if let Some(mir::UnwindAction::Terminate(reason)) = terminator.unwind() {
+            push_callee(mono_lang_item(reason.lang_item()));
+        }
+    }
+
+    MonoCallGraphNode { callees: callees.into_iter().collect(), has_indirect_calls }
+}
+
+pub fn provide(providers: &mut Providers) {
+    providers.mono_call_graph = mono_call_graph;
+}
@@ -194,7 +194,7 @@
 
 use crate::errors::{
     EncounteredErrorWhileInstantiating, LargeAssignmentsLint, NoOptimizedMir, RecursionLimit,
-    TypeLengthLimit,
+    TypeLengthLimit, UnsupportedApiReason, UnsupportedApiUse,
 };
 
 #[derive(PartialEq)]
@@ -282,6 +282,7 @@ pub fn collect_crate_mono_items(
                     &mut recursion_depths,
                     recursion_limit,
                     usage_map,
+                    &mut Vec::new(),
                 );
             });
         });
@@ -330,7 +331,12 @@ fn collect_roots(tcx: TyCtxt<'_>, mode: MonoItemCollectionMode) -> Vec<MonoItem<
 
 /// Collect all monomorphized items reachable from `starting_point`, and emit a note diagnostic if a
 /// post-monomorphization error is encountered during a collection step.
-#[instrument(skip(tcx, visited, recursion_depths, recursion_limit, usage_map), level = "debug")]
+///
+/// `callers` is the path of items through which `starting_point` was reached from its root.
+#[instrument(
+    skip(tcx, visited, recursion_depths, recursion_limit, usage_map, callers),
+    level = "debug"
+)]
 fn collect_items_rec<'tcx>(
     tcx: TyCtxt<'tcx>,
     starting_item: Spanned<MonoItem<'tcx>>,
@@ -338,6 +344,7 @@ fn collect_items_rec<'tcx>(
     recursion_depths: &mut DefIdMap<usize>,
     recursion_limit: Limit,
     usage_map: MTLockRef<'_, UsageMap<'tcx>>,
+    callers: &mut Vec<MonoItem<'tcx>>,
 ) {
     if !visited.lock_mut().insert(starting_item.node) {
         // We've been here already, no need to search again.
@@ -415,7 +422,7 @@ fn collect_items_rec<'tcx>(
             check_type_length_limit(tcx, instance);
 
             rustc_data_structures::stack::ensure_sufficient_stack(|| {
-                collect_used_items(tcx, instance, &mut used_items);
+                collect_used_items(tcx, instance, callers, &mut used_items);
             });
         }
         MonoItem::GlobalAsm(item_id) => {
@@ -470,9 +477,19 @@ fn collect_items_rec<'tcx>(
     }
     usage_map.lock_mut().record_used(starting_item.node, &used_items);
 
+    callers.push(starting_item.node);
     for used_item in used_items {
-        collect_items_rec(tcx, used_item, visited, recursion_depths, recursion_limit, usage_map);
+        collect_items_rec(
+            tcx,
+            used_item,
+            visited,
+            recursion_depths,
+            recursion_limit,
+            usage_map,
+            callers,
+        );
     }
+    callers.pop();
 
     if let Some((def_id, depth)) = recursion_depth_reset {
         recursion_depths.insert(def_id, depth);
@@ -592,6 +609,11 @@ struct MirUsedCollector<'a, 'tcx> {
     body: &'a mir::Body<'tcx>,
     output: &'a mut MonoItems<'tcx>,
     instance: Instance<'tcx>,
+    /// The items through which `instance` was reached, see `collect_items_rec`.
+    callers: &'a [MonoItem<'tcx>],
+    /// Whether uses of APIs that are unsupported on the target are errors, see
+    /// `check_unsupported_api_use`.
+    denies_unsupported_apis: bool,
     /// Spans for move size lints already emitted. Helps avoid duplicate lints.
     move_size_spans: Vec<Span>,
     visiting_call_terminator: bool,
@@ -695,6 +717,50 @@ fn check_fn_args_move_size(
             self.check_operand_move_size(arg, location);
         }
     }
+
+    /// Reports a call to, or a reification of, a function that is marked with
+    /// `#[rustc_unsupported_on]` for the current target. Such functions fail or panic at runtime,
+    /// so targets can ask for the uses that end up in a final artifact to be rejected instead.
+    ///
+    /// Only the functions that are codegened in the current crate are checked, which includes the
+    /// generic and `#[inline]` functions of upstream crates, but not their other functions. Uses
+    /// in those are reported by the `unsupported_on_target` lint when the upstream crate is built.
+    fn check_unsupported_api_use(&self, fn_ty: Ty<'tcx>, span: Span) {
+        if !self.denies_unsupported_apis {
+            return;
+        }
+        let ty::FnDef(def_id, _) = *fn_ty.kind() else { return };
+        let Some(restriction) = self.tcx.target_restriction(def_id, sym::rustc_unsupported_on)
+        else {
+            return;
+        };
+        // Unsupported APIs call each other, e.g. `fs::read` calls `fs::read::inner`. Only the use
+        // from outside of them is reported.
+        if self.tcx.target_restriction(self.instance.def_id(), sym::rustc_unsupported_on).is_some()
+        {
+            return;
+        }
+
+        let call_path = self
+            .callers
+            .iter()
+            .chain([&MonoItem::Fn(self.instance)])
+            .map(|item| match item {
+                MonoItem::Fn(instance) => with_no_trimmed_paths!(format!("`{instance}`")),
+                _ => with_no_trimmed_paths!(format!("`{item}`")),
+            })
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        self.tcx.sess.emit_err(UnsupportedApiUse {
+            span,
+            path: self.tcx.def_path_str(def_id),
+            target_os: restriction.target_os,
+            reason: restriction.reason.map(|reason| UnsupportedApiReason { reason }),
+            user: with_no_trimmed_paths!(self.instance.to_string()),
+            user_crate: self.tcx.crate_name(self.instance.def_id().krate),
+            call_path,
+        });
+    }
 }
 
 impl<'a, 'tcx> MirVisitor<'tcx> for MirUsedCollector<'a, 'tcx> {
@@ -740,6 +806,7 @@ fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
             ) => {
                 let fn_ty = operand.ty(self.body, self.tcx);
                 let fn_ty = self.monomorphize(fn_ty);
+                self.check_unsupported_api_use(fn_ty, span);
                 visit_fn_use(self.tcx, fn_ty, false, span, &mut self.output);
             }
             mir::Rvalue::Cast(
@@ -816,6 +883,7 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
                 let callee_ty = func.ty(self.body, tcx);
                 let callee_ty = self.monomorphize(callee_ty);
                 self.check_fn_args_move_size(callee_ty, args, location);
+                self.check_unsupported_api_use(callee_ty, source);
                 visit_fn_use(self.tcx, callee_ty, true, source, &mut self.output)
             }
             mir::TerminatorKind::Drop { ref place, .. } => {
@@ -1410,10 +1478,11 @@ fn build_skip_move_check_fns(tcx: TyCtxt<'_>) -> Vec<DefId> {
 }
 
 /// Scans the MIR in order to find function calls, closures, and drop-glue.
-#[instrument(skip(tcx, output), level = "debug")]
+#[instrument(skip(tcx, callers, output), level = "debug")]
 fn collect_used_items<'tcx>(
     tcx: TyCtxt<'tcx>,
     instance: Instance<'tcx>,
+    callers: &[MonoItem<'tcx>],
     output: &mut MonoItems<'tcx>,
 ) {
     let body = tcx.instance_mir(instance.def);
@@ -1425,6 +1494,8 @@ fn collect_used_items<'tcx>(
         body: &body,
         output,
         instance,
+        callers,
+        denies_unsupported_apis: tcx.denies_unsupported_apis(),
         move_size_spans: vec![],
         visiting_call_terminator: false,
         skip_move_check_fns: None,
@@ -3,7 +3,7 @@
 use crate::fluent_generated as fluent;
 use rustc_errors::ErrorGuaranteed;
 use rustc_errors::IntoDiagnostic;
-use rustc_macros::{Diagnostic, LintDiagnostic};
+use rustc_macros::{Diagnostic, LintDiagnostic, Subdiagnostic};
 use rustc_span::{Span, Symbol};
 
 #[derive(Diagnostic)]
@@ -102,3 +102,25 @@ pub struct EncounteredErrorWhileInstantiating {
 pub struct UnknownCguCollectionMode<'a> {
     pub mode: &'a str,
 }
+
+#[derive(Diagnostic)]
+#[diag(monomorphize_unsupported_api_use)]
+#[note(monomorphize_unsupported_api_user)]
+#[note(monomorphize_unsupported_api_call_path)]
+pub struct UnsupportedApiUse {
+    #[primary_span]
+    pub span: Span,
+    pub path: String,
+    pub target_os: Symbol,
+    #[subdiagnostic]
+    pub reason: Option<UnsupportedApiReason>,
+    pub user: String,
+    pub user_crate: Symbol,
+    pub call_path: String,
+}
+
+#[derive(Subdiagnostic)]
+#[note(monomorphize_unsupported_api_reason)]
+pub struct UnsupportedApiReason {
+    pub reason: Symbol,
+}
@@ -423,6 +423,17 @@ passes_layout_of =
 passes_layout_size =
     size: {$size}
 
+passes_layout_snapshot_changed =
+    layout of `{$ty}` differs from the layout snapshot in `{$path}`
+    .recorded = recorded layout: {$recorded}
+    .current = current layout: {$current}
+
+passes_layout_snapshot_io =
+    failed to {$action} layout snapshot `{$path}`: {$err}
+
+passes_layout_snapshot_missing =
+    `{$ty}` is recorded in the layout snapshot in `{$path}`, but is not a public type of this crate anymore
+
 passes_link =
     attribute should be applied to an `extern` block with non-Rust ABI
     .warn = {-passes_previously_accepted}
@@ -454,6 +465,14 @@ passes_macro_use =
     `#[{$name}]` only has an effect on `extern crate` and modules
 
 passes_maybe_string_interpolation = you might have meant to use string interpolation in this string literal
+passes_mir_inline_malformed =
+    malformed `mir_inline` attribute input
+    .note = expected `#[mir_inline(always)]` or `#[mir_inline(cost = N)]` with an unsuffixed integer `N`
+
+passes_mir_inline_never =
+    `#[mir_inline]` cannot be used on a function that is `#[inline(never)]`
+    .label = `#[inline(never)]` here
+
 passes_missing_const_err =
     attributes `#[rustc_const_unstable]` and `#[rustc_const_stable]` require the function or method to be `const`
     .help = make the function or method const
@@ -644,6 +663,14 @@ passes_rustc_safe_intrinsic =
     attribute should be applied to intrinsic functions
     .label = not an intrinsic function
 
+passes_rustc_target_restriction_malformed =
+    malformed `{$name}` attribute input
+    .note = expected `#[{$name}(target_os = "...")]` with an optional `reason = "..."`
+
+passes_rustc_target_restriction_target =
+    `#[{$name}]` should be applied to a function, method, implementation block or module
+    .label = not a function, method, implementation block or module
+
 passes_rustc_std_internal_symbol =
     attribute should be applied to functions or statics
     .label = not a function or static
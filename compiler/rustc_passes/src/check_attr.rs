@@ -6,6 +6,7 @@
 
 use crate::{errors, fluent_generated as fluent};
 use rustc_ast::{ast, AttrStyle, Attribute, LitKind, MetaItemKind, MetaItemLit, NestedMetaItem};
+use rustc_attr::list_contains_name;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, IntoDiagnosticArg, MultiSpan};
 use rustc_feature::{AttributeDuplicates, AttributeType, BuiltinAttribute, BUILTIN_ATTRIBUTE_MAP};
@@ -116,6 +117,7 @@ fn check_attributes(
                 sym::do_not_recommend => self.check_do_not_recommend(attr.span, target),
                 sym::inline => self.check_inline(hir_id, attr, span, target),
                 sym::coverage => self.check_coverage(hir_id, attr, span, target),
+                sym::mir_inline => self.check_mir_inline(hir_id, attr, span, target, attrs),
                 sym::non_exhaustive => self.check_non_exhaustive(hir_id, attr, span, target),
                 sym::marker => self.check_marker(hir_id, attr, span, target),
                 sym::target_feature => self.check_target_feature(hir_id, attr, span, target, attrs),
@@ -163,6 +165,9 @@ fn check_attributes(
                 sym::rustc_lint_opt_deny_field_access => {
                     self.check_rustc_lint_opt_deny_field_access(&attr, span, target)
                 }
+                sym::rustc_unsupported_on | sym::rustc_expensive_on => {
+                    self.check_rustc_target_restriction(&attr, span, target)
+                }
                 sym::rustc_clean
                 | sym::rustc_dirty
                 | sym::rustc_if_this_changed
@@ -352,6 +357,46 @@ fn check_inline(&self, hir_id: HirId, attr: &Attribute, span: Span, target: Targ
         }
     }
 
+    /// Checks that `#[mir_inline]` is applied to a function with a body, that its argument is
+    /// well-formed, and that the function isn't also `#[inline(never)]`.
+    fn check_mir_inline(
+        &self,
+        hir_id: HirId,
+        attr: &Attribute,
+        span: Span,
+        target: Target,
+        attrs: &[Attribute],
+    ) -> bool {
+        if !matches!(
+            target,
+            Target::Fn | Target::Method(MethodKind::Trait { body: true } | MethodKind::Inherent)
+        ) {
+            self.tcx.sess.emit_err(errors::AttrShouldBeAppliedToFn {
+                attr_span: attr.span,
+                defn_span: span,
+                on_crate: hir_id == CRATE_HIR_ID,
+            });
+            return false;
+        }
+        // An attribute without a list has already been reported as not matching the template.
+        if attr.meta_item_list().is_none() {
+            return false;
+        }
+        if rustc_attr::parse_mir_inline(attr).is_none() {
+            self.tcx.sess.emit_err(errors::MirInlineMalformed { span: attr.span });
+            return false;
+        }
+        let is_inline_never = |a: &&Attribute| {
+            a.has_name(sym::inline)
+                && a.meta_item_list().is_some_and(|list| list_contains_name(&list, sym::never))
+        };
+        if let Some(never) = attrs.iter().find(is_inline_never) {
+            self.tcx.sess.emit_err(errors::MirInlineNever { span: attr.span, never: never.span });
+            return false;
+        }
+        true
+    }
+
     /// Checks if a `#[coverage]` is applied directly to a function
     fn check_coverage(&self, hir_id: HirId, attr: &Attribute, span: Span, target: Target) -> bool {
         match target {
@@ -1645,6 +1690,29 @@ fn check_rustc_lint_opt_ty(&self, attr: &Attribute, span: Span, target: Target)
         }
     }
 
+    /// Checks that the `#[rustc_unsupported_on]` and `#[rustc_expensive_on]` attributes are
+    /// well-formed and only applied to a function, method, implementation block or module.
+    fn check_rustc_target_restriction(&self, attr: &Attribute, span: Span, target: Target) -> bool {
+        let name = attr.name_or_empty();
+        if rustc_attr::parse_target_restriction(attr).is_none() {
+            self.tcx
+                .sess
+                .emit_err(errors::RustcTargetRestrictionMalformed { span: attr.span, name });
+            return false;
+        }
+        match target {
+            Target::Fn | Target::Method(_) | Target::Impl | Target::Mod => true,
+            _ => {
+                self.tcx.sess.emit_err(errors::RustcTargetRestrictionTarget {
+                    attr_span: attr.span,
+                    span,
+                    name,
+                });
+                false
+            }
+        }
+    }
+
     /// Checks that the `#[rustc_lint_opt_deny_field_access]` attribute is only applied to a field.
     fn check_rustc_lint_opt_deny_field_access(
         &self,
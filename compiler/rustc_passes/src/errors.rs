@@ -63,6 +63,23 @@ pub struct InlineNotFnOrClosure {
     pub defn_span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_mir_inline_malformed)]
+#[note]
+pub struct MirInlineMalformed {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_mir_inline_never)]
+pub struct MirInlineNever {
+    #[primary_span]
+    pub span: Span,
+    #[label]
+    pub never: Span,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(passes_coverage_ignored_function_prototype)]
 pub struct IgnoredCoverageFnProto;
@@ -784,6 +801,25 @@ pub struct RustcLintOptDenyFieldAccess {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_rustc_target_restriction_malformed)]
+#[note]
+pub struct RustcTargetRestrictionMalformed {
+    #[primary_span]
+    pub span: Span,
+    pub name: Symbol,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_rustc_target_restriction_target)]
+pub struct RustcTargetRestrictionTarget {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub span: Span,
+    pub name: Symbol,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_collapse_debuginfo)]
 pub struct CollapseDebuginfo {
@@ -945,6 +981,34 @@ pub struct LayoutOf {
     pub ty_layout: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_layout_snapshot_changed)]
+#[note(passes_recorded)]
+#[note(passes_current)]
+pub struct LayoutSnapshotChanged<'a> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: &'a str,
+    pub path: &'a Path,
+    pub recorded: &'a str,
+    pub current: &'a str,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_layout_snapshot_missing)]
+pub struct LayoutSnapshotMissing<'a> {
+    pub ty: &'a str,
+    pub path: &'a Path,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_layout_snapshot_io)]
+pub struct LayoutSnapshotIo<'a> {
+    pub action: &'static str,
+    pub path: &'a Path,
+    pub err: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_layout_invalid_attribute)]
 pub struct LayoutInvalidAttribute {
@@ -0,0 +1,147 @@
+//! Implements `-Z layout-snapshot`, which records the layouts of the public types of a
+//! crate in a file, so that layout changes between compiler versions can be caught by
+//! checking against it with `-Z layout-snapshot-check`.
+//!
+//! The file has one line per type, with the path of the type followed by its layout,
+//! and lines starting with `#` are ignored. Only types without type or const parameters
+//! are recorded, as other types do not have a layout of their own.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{GenericArgs, ParamEnv, TyCtxt};
+use rustc_target::abi::{LayoutS, TagEncoding, VariantIdx, Variants};
+
+use crate::errors::{LayoutSnapshotChanged, LayoutSnapshotIo, LayoutSnapshotMissing};
+
+pub fn layout_snapshot(tcx: TyCtxt<'_>) {
+    let Some(path) = &tcx.sess.opts.unstable_opts.layout_snapshot else {
+        return;
+    };
+
+    let layouts = with_no_trimmed_paths!(public_layouts(tcx));
+
+    if !tcx.sess.opts.unstable_opts.layout_snapshot_check {
+        let target = &tcx.sess.opts.target_triple;
+        let mut snapshot = format!("# layout snapshot for target {target}\n");
+        for (ty, (_, layout)) in &layouts {
+            writeln!(snapshot, "{ty} {layout}").unwrap();
+        }
+        if let Err(err) = fs::write(path, snapshot) {
+            tcx.sess.emit_err(LayoutSnapshotIo { action: "write", path, err: err.to_string() });
+        }
+        return;
+    }
+
+    let snapshot = match fs::read_to_string(path) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            tcx.sess.emit_err(LayoutSnapshotIo { action: "read", path, err: err.to_string() });
+            return;
+        }
+    };
+    let recorded = snapshot
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split_once(' ').unwrap_or((line, "")));
+    for (ty, recorded) in recorded {
+        match layouts.get(ty) {
+            Some((def_id, current)) if current != recorded => {
+                tcx.sess.emit_err(LayoutSnapshotChanged {
+                    span: tcx.def_span(*def_id),
+                    ty,
+                    path,
+                    recorded,
+                    current,
+                });
+            }
+            Some(_) => {}
+            None => {
+                tcx.sess.emit_err(LayoutSnapshotMissing { ty, path });
+            }
+        }
+    }
+}
+
+/// Returns the layouts of all public types that have one, by the path of the type.
+fn public_layouts(tcx: TyCtxt<'_>) -> BTreeMap<String, (LocalDefId, String)> {
+    let effective_visibilities = tcx.effective_visibilities(());
+    let mut layouts = BTreeMap::new();
+    for def_id in tcx.hir_crate_items(()).definitions() {
+        if !matches!(tcx.def_kind(def_id), DefKind::Struct | DefKind::Enum | DefKind::Union)
+            || !effective_visibilities.is_exported(def_id)
+            || tcx.generics_of(def_id).requires_monomorphization(tcx)
+        {
+            continue;
+        }
+        if let Some(layout) = describe_layout(tcx, def_id) {
+            layouts.insert(tcx.def_path_str(def_id), (def_id, layout));
+        }
+    }
+    layouts
+}
+
+/// Describes the layout of the ADT `def_id` on a single line, for example
+/// `size 8, align 4 { x: u32 @ 0, y: u32 @ 4 }` for a struct.
+///
+/// For enums, this also includes where the tag is stored, and the
+/// discriminant and fields of each variant.
+fn describe_layout(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Option<String> {
+    let adt = tcx.adt_def(def_id);
+    let args = GenericArgs::identity_for_item(tcx, def_id);
+    let ty = tcx.type_of(def_id).instantiate_identity();
+    // Types whose layout cannot be computed are left out, the error about them
+    // is reported when they are used.
+    let layout = tcx.layout_of(ParamEnv::reveal_all().and(ty)).ok()?;
+
+    let mut out = format!("size {}, align {}", layout.size.bytes(), layout.align.abi.bytes());
+    let describe_fields = |out: &mut String, index: VariantIdx, variant_layout: &LayoutS<_, _>| {
+        let variant = adt.variant(index);
+        out.push_str(" {");
+        for (i, field) in variant.fields.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            let offset = variant_layout.fields.offset(i).bytes();
+            write!(out, "{sep}{}: {} @ {offset}", field.name, field.ty(tcx, args)).unwrap();
+        }
+        out.push_str(if variant.fields.is_empty() { "}" } else { " }" });
+    };
+
+    match &layout.variants {
+        Variants::Single { index } => {
+            if adt.is_enum() {
+                if let Some(variant) = adt.variants().get(*index) {
+                    write!(out, "; {}", variant.name).unwrap();
+                    describe_fields(&mut out, *index, *layout);
+                }
+            } else {
+                describe_fields(&mut out, *index, *layout);
+            }
+        }
+        Variants::Multiple { tag, tag_encoding, tag_field, variants } => {
+            let tag_size = tag.size(&tcx).bytes();
+            let tag_offset = layout.fields.offset(*tag_field).bytes();
+            match tag_encoding {
+                TagEncoding::Direct => {
+                    write!(out, ", tag {tag_size} @ {tag_offset}").unwrap();
+                }
+                TagEncoding::Niche { untagged_variant, niche_start, .. } => {
+                    write!(
+                        out,
+                        ", niche {tag_size} @ {tag_offset} from {niche_start}, untagged {}",
+                        adt.variant(*untagged_variant).name,
+                    )
+                    .unwrap();
+                }
+            }
+            for (index, discr) in adt.discriminants(tcx) {
+                write!(out, "; {} = {discr}", adt.variant(index).name).unwrap();
+                describe_fields(&mut out, index, &variants[index]);
+            }
+        }
+    }
+    Some(out)
+}
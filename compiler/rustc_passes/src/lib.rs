@@ -38,6 +38,7 @@
 pub mod hir_id_validator;
 pub mod hir_stats;
 mod lang_items;
+pub mod layout_snapshot;
 pub mod layout_test;
 mod lib_features;
 mod liveness;
@@ -26,6 +26,11 @@ session_file_is_not_writeable = output file {$file} is not writeable -- check it
 
 session_file_write_fail = failed to write `{$path}` due to error `{$err}`
 
+session_fix_conflict = machine-applicable suggestion left out of `{$path}` because it overlaps with another one
+    .note = this suggestion was included instead
+
+session_fix_normalized_file = machine-applicable suggestions for `{$file}` left out of `{$path}` because it has CRLF line endings or a byte order mark
+
 session_hexadecimal_float_literal_not_supported = hexadecimal float literal is not supported
 
 session_incompatible_linker_flavor = linker flavor `{$flavor}` is incompatible with the current target
@@ -71,6 +76,8 @@ session_nul_in_c_str = null characters in C string literals are not supported
 session_octal_float_literal_not_supported = octal float literal is not supported
 session_optimization_fuel_exhausted = optimization-fuel-exhausted: {$msg}
 
+session_print_unreachable_items_requires_lto = `-Zprint-unreachable-items` requires `-Clto`
+
 session_profile_sample_use_file_does_not_exist = file `{$path}` passed to `-C profile-sample-use` does not exist.
 
 session_profile_use_file_does_not_exist = file `{$path}` passed to `-C profile-use` does not exist.
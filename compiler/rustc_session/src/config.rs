@@ -437,6 +437,7 @@ pub enum OutputType {
     Object,
     Exe,
     DepInfo,
+    LinkMap,
 }
 
 // Safety: Trivial C-Style enums have a stable sort order across compilation sessions.
@@ -455,7 +456,10 @@ fn to_stable_hash_key(&self, _: &HCX) -> Self::KeyType {
 impl OutputType {
     fn is_compatible_with_codegen_units_and_single_output_file(&self) -> bool {
         match *self {
-            OutputType::Exe | OutputType::DepInfo | OutputType::Metadata => true,
+            OutputType::Exe
+            | OutputType::DepInfo
+            | OutputType::Metadata
+            | OutputType::LinkMap => true,
             OutputType::Bitcode
             | OutputType::Assembly
             | OutputType::LlvmAssembly
@@ -474,6 +478,7 @@ pub fn shorthand(&self) -> &'static str {
             OutputType::Metadata => "metadata",
             OutputType::Exe => "link",
             OutputType::DepInfo => "dep-info",
+            OutputType::LinkMap => "link-map",
         }
     }
 
@@ -487,13 +492,14 @@ fn from_shorthand(shorthand: &str) -> Option<Self> {
             "metadata" => OutputType::Metadata,
             "link" => OutputType::Exe,
             "dep-info" => OutputType::DepInfo,
+            "link-map" => OutputType::LinkMap,
             _ => return None,
         })
     }
 
     fn shorthands_display() -> String {
         format!(
-            "`{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`",
+            "`{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`, `{}`",
             OutputType::Bitcode.shorthand(),
             OutputType::Assembly.shorthand(),
             OutputType::LlvmAssembly.shorthand(),
@@ -502,6 +508,7 @@ fn shorthands_display() -> String {
             OutputType::Metadata.shorthand(),
             OutputType::Exe.shorthand(),
             OutputType::DepInfo.shorthand(),
+            OutputType::LinkMap.shorthand(),
         )
     }
 
@@ -515,6 +522,7 @@ pub fn extension(&self) -> &'static str {
             OutputType::Metadata => "rmeta",
             OutputType::DepInfo => "d",
             OutputType::Exe => "",
+            OutputType::LinkMap => "map",
         }
     }
 
@@ -523,7 +531,8 @@ pub fn is_text_output(&self) -> bool {
             OutputType::Assembly
             | OutputType::LlvmAssembly
             | OutputType::Mir
-            | OutputType::DepInfo => true,
+            | OutputType::DepInfo
+            | OutputType::LinkMap => true,
             OutputType::Bitcode | OutputType::Object | OutputType::Metadata | OutputType::Exe => {
                 false
             }
@@ -544,6 +553,8 @@ pub enum ErrorOutputType {
         /// human output.
         json_rendered: HumanReadableErrorType,
     },
+    /// A SARIF log, for static analysis and code scanning tools.
+    Sarif,
 }
 
 impl Default for ErrorOutputType {
@@ -620,7 +631,8 @@ pub fn should_codegen(&self) -> bool {
             | OutputType::LlvmAssembly
             | OutputType::Mir
             | OutputType::Object
-            | OutputType::Exe => true,
+            | OutputType::Exe
+            | OutputType::LinkMap => true,
             OutputType::Metadata | OutputType::DepInfo => false,
         })
     }
@@ -635,7 +647,7 @@ pub fn should_link(&self) -> bool {
             | OutputType::Metadata
             | OutputType::Object
             | OutputType::DepInfo => false,
-            OutputType::Exe => true,
+            OutputType::Exe | OutputType::LinkMap => true,
         })
     }
 }
@@ -753,6 +765,8 @@ pub enum PrintKind {
     LinkArgs,
     SplitDebuginfo,
     DeploymentTarget,
+    StackUsage,
+    StdCapabilities,
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
@@ -930,7 +944,11 @@ pub fn path(&self, flavor: OutputType) -> OutFileName {
         self.outputs
             .get(&flavor)
             .and_then(|p| p.to_owned())
-            .or_else(|| self.single_output_file.clone())
+            .or_else(|| match flavor {
+                // The link map goes next to the linked output that `-o` names.
+                OutputType::LinkMap => None,
+                _ => self.single_output_file.clone(),
+            })
             .unwrap_or_else(|| OutFileName::Real(self.output_path(flavor)))
     }
 
@@ -1163,6 +1181,7 @@ pub fn diagnostic_handler_flags(&self, can_emit_warnings: bool) -> HandlerFlags
             macro_backtrace: self.macro_backtrace,
             deduplicate_diagnostics: self.deduplicate_diagnostics,
             track_diagnostics: self.track_diagnostics,
+            collect_fixes: self.emit_fixes.is_some(),
         }
     }
 }
@@ -1789,7 +1808,7 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
             "",
             "error-format",
             "How errors and other messages are produced",
-            "human|json|short",
+            "human|json|sarif|short",
         ),
         opt::multi_s("", "json", "Configure the JSON output of the compiler", "CONFIG"),
         opt::opt_s(
@@ -1961,6 +1980,7 @@ pub fn parse_error_format(
             }
             Some("json") => ErrorOutputType::Json { pretty: false, json_rendered },
             Some("pretty-json") => ErrorOutputType::Json { pretty: true, json_rendered },
+            Some("sarif") => ErrorOutputType::Sarif,
             Some("short") => ErrorOutputType::HumanReadable(HumanReadableErrorType::Short(color)),
 
             Some(arg) => {
@@ -1968,8 +1988,8 @@ pub fn parse_error_format(
                     HumanReadableErrorType::Default(color),
                 ));
                 handler.early_error(format!(
-                    "argument for `--error-format` must be `human`, `json` or \
-                     `short` (instead was `{arg}`)"
+                    "argument for `--error-format` must be `human`, `json`, \
+                     `sarif` or `short` (instead was `{arg}`)"
                 ))
             }
         }
@@ -2042,6 +2062,13 @@ fn check_error_format_stability(
             });
             handler.early_error("`--error-format=human-annotate-rs` is unstable");
         }
+        if let ErrorOutputType::Sarif = error_format {
+            handler.abort_if_error_and_set_error_format(ErrorOutputType::Json {
+                pretty: false,
+                json_rendered,
+            });
+            handler.early_error("`--error-format=sarif` is unstable");
+        }
     }
 }
 
@@ -2061,12 +2088,16 @@ fn parse_output_types(
                         display = OutputType::shorthands_display(),
                     ))
                 });
+                if output_type == OutputType::LinkMap && !unstable_opts.unstable_options {
+                    handler.early_error("`--emit=link-map` is unstable");
+                }
                 output_types.insert(output_type, path);
             }
         }
     };
-    if output_types.is_empty() {
-        output_types.insert(OutputType::Exe, None);
+    // The link map is written by the linker, so it needs linking.
+    if output_types.is_empty() || output_types.contains_key(&OutputType::LinkMap) {
+        output_types.entry(OutputType::Exe).or_insert(None);
     }
     OutputTypes(output_types)
 }
@@ -2170,6 +2201,8 @@ fn collect_print_requests(
         ("link-args", PrintKind::LinkArgs),
         ("split-debuginfo", PrintKind::SplitDebuginfo),
         ("deployment-target", PrintKind::DeploymentTarget),
+        ("stack-usage", PrintKind::StackUsage),
+        ("std-capabilities", PrintKind::StdCapabilities),
     ];
 
     // We disallow reusing the same path in multiple prints, such as `--print
@@ -2202,6 +2235,26 @@ fn collect_print_requests(
                     );
                 }
             }
+            Some((_, PrintKind::StackUsage)) => {
+                if unstable_opts.unstable_options {
+                    PrintKind::StackUsage
+                } else {
+                    handler.early_error(
+                        "the `-Z unstable-options` flag must also be passed to \
+                         enable the stack-usage print option",
+                    );
+                }
+            }
+            Some((_, PrintKind::StdCapabilities)) => {
+                if unstable_opts.unstable_options {
+                    PrintKind::StdCapabilities
+                } else {
+                    handler.early_error(
+                        "the `-Z unstable-options` flag must also be passed to \
+                         enable the std-capabilities print option",
+                    );
+                }
+            }
             Some(&(_, print_kind)) => print_kind,
             None => {
                 let prints =
@@ -2652,6 +2705,16 @@ pub fn build_session_options(
     if unstable_opts.profile && incremental.is_some() {
         handler.early_error("can't instrument with gcov profiling when compiling incrementally");
     }
+    if unstable_opts.deterministic_output && incremental.is_some() {
+        // Objects reused from the incremental cache are partitioned, and named, differently
+        // from freshly built ones.
+        handler.early_error("can't produce deterministic output when compiling incrementally");
+    }
+    if unstable_opts.deterministic_output
+        && unstable_opts.remap_path_scope != RemapPathScopeComponents::all()
+    {
+        handler.early_error("`-Z deterministic-output` requires `-Z remap-path-scope=all`");
+    }
     if unstable_opts.profile {
         match codegen_units {
             Some(1) => {}
@@ -2816,7 +2879,7 @@ pub fn build_session_options(
 
     let crate_name = matches.opt_str("crate-name");
 
-    let remap_path_prefix = parse_remap_path_prefix(handler, matches, &unstable_opts);
+    let mut remap_path_prefix = parse_remap_path_prefix(handler, matches, &unstable_opts);
 
     let pretty = parse_pretty(handler, &unstable_opts);
 
@@ -2857,6 +2920,17 @@ pub fn build_session_options(
         handler.early_error(format!("Current directory is invalid: {e}"));
     });
 
+    if unstable_opts.deterministic_output {
+        // The last matching mapping wins, so these go first to let the mappings given on the
+        // command line override them. The sysroot comes after the working directory, as it is
+        // often inside of it.
+        let defaults = [
+            (working_dir.clone(), PathBuf::from(".")),
+            (sysroot.to_path_buf(), PathBuf::from("/rustc/sysroot")),
+        ];
+        remap_path_prefix.splice(0..0, defaults);
+    }
+
     let remap = file_path_mapping(remap_path_prefix.clone(), &unstable_opts);
     let (path, remapped) = remap.map_prefix(&working_dir);
     let working_dir = if remapped {
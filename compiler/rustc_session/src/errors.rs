@@ -125,6 +125,10 @@ pub struct CannotMixAndMatchSanitizers {
 #[diag(session_unstable_virtual_function_elimination)]
 pub struct UnstableVirtualFunctionElimination;
 
+#[derive(Diagnostic)]
+#[diag(session_print_unreachable_items_requires_lto)]
+pub struct PrintUnreachableItemsRequiresLto;
+
 #[derive(Diagnostic)]
 #[diag(session_unsupported_dwarf_version)]
 pub struct UnsupportedDwarfVersion {
@@ -161,6 +165,23 @@ pub(crate) struct FileWriteFail<'a> {
     pub err: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(session_fix_conflict)]
+pub(crate) struct FixConflict<'a> {
+    #[primary_span]
+    pub span: Span,
+    #[note]
+    pub other: Span,
+    pub path: &'a std::path::Path,
+}
+
+#[derive(Diagnostic)]
+#[diag(session_fix_normalized_file)]
+pub(crate) struct FixNormalizedFile<'a> {
+    pub file: String,
+    pub path: &'a std::path::Path,
+}
+
 #[derive(Diagnostic)]
 #[diag(session_crate_name_does_not_match)]
 pub struct CrateNameDoesNotMatch {
@@ -430,6 +430,8 @@ mod desc {
     pub const parse_remap_path_scope: &str = "comma separated list of scopes: `macro`, `diagnostics`, `unsplit-debuginfo`, `split-debuginfo`, `split-debuginfo-path`, `object`, `all`";
     pub const parse_inlining_threshold: &str =
         "either a boolean (`yes`, `no`, `on`, `off`, etc), or a non-negative number";
+    pub const parse_section_sizes: &str = "a comma separated list of `section:size` pairs, where \
+        the size is a number of bytes, optionally followed by `K`, `M`, or `G`";
 }
 
 mod parse {
@@ -574,6 +576,27 @@ pub(crate) fn parse_list_with_polarity(
         }
     }
 
+    pub(crate) fn parse_section_sizes(slot: &mut Vec<(String, u64)>, v: Option<&str>) -> bool {
+        let Some(v) = v else { return false };
+        for limit in v.split(',') {
+            let Some((section, size)) = limit.rsplit_once(':') else { return false };
+            let (digits, unit) = match size.as_bytes().last() {
+                Some(b'K') => (&size[..size.len() - 1], 1 << 10),
+                Some(b'M') => (&size[..size.len() - 1], 1 << 20),
+                Some(b'G') => (&size[..size.len() - 1], 1 << 30),
+                _ => (size, 1),
+            };
+            let Some(size) = digits.parse::<u64>().ok().and_then(|n| n.checked_mul(unit)) else {
+                return false;
+            };
+            if section.is_empty() {
+                return false;
+            }
+            slot.push((section.to_string(), size));
+        }
+        true
+    }
+
     pub(crate) fn parse_location_detail(ld: &mut LocationDetail, v: Option<&str>) -> bool {
         if let Some(v) = v {
             ld.line = false;
@@ -1511,6 +1534,10 @@ pub(crate) fn parse_inlining_threshold(slot: &mut InliningThreshold, v: Option<&
         "compress debug info sections (none, zlib, zstd, default: none)"),
     deduplicate_diagnostics: bool = (true, parse_bool, [UNTRACKED],
         "deduplicate identical diagnostics (default: yes)"),
+    deterministic_output: bool = (false, parse_bool, [TRACKED],
+        "make the output independent of the build environment: remap the working directory \
+        and the sysroot, link objects and sections in a stable order, and omit build IDs \
+        (default: no)"),
     dep_info_omit_d_target: bool = (false, parse_bool, [TRACKED],
         "in dep-info output, omit targets for tracking dependencies of the dep-info files \
         themselves (default: no)"),
@@ -1555,6 +1582,8 @@ pub(crate) fn parse_inlining_threshold(slot: &mut InliningThreshold, v: Option<&
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
     dylib_lto: bool = (false, parse_bool, [UNTRACKED],
         "enables LTO for dylib crate type"),
+    emit_fixes: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "write the machine-applicable suggestions of all diagnostics to this file as a patch"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
     emit_thin_lto: bool = (true, parse_bool, [TRACKED],
@@ -1626,6 +1655,11 @@ pub(crate) fn parse_inlining_threshold(slot: &mut InliningThreshold, v: Option<&
         "keep hygiene data after analysis (default: no)"),
     layout_seed: Option<u64> = (None, parse_opt_number, [TRACKED],
         "seed layout randomization"),
+    layout_snapshot: Option<PathBuf> = (None, parse_opt_pathbuf, [TRACKED],
+        "write the layouts of all public types of the crate to this file"),
+    layout_snapshot_check: bool = (false, parse_bool, [TRACKED],
+        "check the layouts of public types against the `-Z layout-snapshot` file instead of \
+        writing it (default: no)"),
     link_directives: bool = (true, parse_bool, [TRACKED],
         "honor #[link] directives in the compiled crate (default: yes)"),
     link_native_libraries: bool = (true, parse_bool, [UNTRACKED],
@@ -1645,6 +1679,9 @@ pub(crate) fn parse_inlining_threshold(slot: &mut InliningThreshold, v: Option<&
         (space separated)"),
     macro_backtrace: bool = (false, parse_bool, [UNTRACKED],
         "show macro backtraces (default: no)"),
+    max_section_size: Vec<(String, u64)> = (Vec::new(), parse_section_sizes, [UNTRACKED],
+        "fail if a section of the linked output is larger than the given size, e.g. \
+        `.text:2M,.data:256K`"),
     maximal_hir_to_mir_coverage: bool = (false, parse_bool, [TRACKED],
         "save as much information as possible about the correspondence between MIR and HIR \
         as source scopes (default: no)"),
@@ -1740,6 +1777,8 @@ pub(crate) fn parse_inlining_threshold(slot: &mut InliningThreshold, v: Option<&
         "print the result of the monomorphization collection pass"),
     print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print layout information for each type encountered (default: no)"),
+    print_unreachable_items: bool = (false, parse_bool, [UNTRACKED],
+        "print the functions exported by dependencies that fat LTO finds unreachable (default: no)"),
     print_vtable_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print size comparison between old and new vtable layouts (default: no)"),
     proc_macro_backtrace: bool = (false, parse_bool, [UNTRACKED],
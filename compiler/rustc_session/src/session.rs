@@ -23,6 +23,7 @@
 use rustc_errors::emitter::{DynEmitter, EmitterWriter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SarifEmitter;
 use rustc_errors::{
     error_code, fallback_fluent_bundle, DiagnosticBuilder, DiagnosticId, DiagnosticMessage,
     ErrorGuaranteed, FluentBundle, Handler, IntoDiagnostic, LazyFallbackBundle, MultiSpan, Noted,
@@ -31,7 +32,7 @@
 use rustc_macros::HashStable_Generic;
 pub use rustc_span::def_id::StableCrateId;
 use rustc_span::edition::Edition;
-use rustc_span::source_map::{FileLoader, RealFileLoader, SourceMap};
+use rustc_span::source_map::{FileLoader, FilePathMapping, RealFileLoader, SourceMap};
 use rustc_span::{SourceFileHashAlgorithm, Span, Symbol};
 use rustc_target::asm::InlineAsmArch;
 use rustc_target::spec::{CodeModel, PanicStrategy, RelocModel, RelroLevel};
@@ -297,10 +298,33 @@ fn check_miri_unleashed_features(&self) {
     /// Invoked all the way at the end to finish off diagnostics printing.
     pub fn finish_diagnostics(&self, registry: &Registry) {
         self.check_miri_unleashed_features();
+        self.emit_fixes();
         self.diagnostic().print_error_count(registry);
         self.emit_future_breakage();
     }
 
+    fn emit_fixes(&self) {
+        let Some(path) = &self.opts.unstable_opts.emit_fixes else {
+            return;
+        };
+
+        let fixes = self.diagnostic().take_fixes();
+        let patch = rustc_errors::fixes::make_patch(self.source_map(), &fixes);
+        for conflict in patch.conflicts {
+            self.emit_warning(errors::FixConflict {
+                span: conflict.span,
+                other: conflict.other,
+                path,
+            });
+        }
+        for file in patch.normalized_files {
+            self.emit_warning(errors::FixNormalizedFile { file, path });
+        }
+        if let Err(e) = std::fs::write(path, patch.diff) {
+            self.emit_err(errors::FileWriteFail { path, err: e.to_string() });
+        }
+    }
+
     fn emit_future_breakage(&self) {
         if !self.opts.json_future_incompat {
             return;
@@ -1315,6 +1339,7 @@ fn default_emitter(
     source_map: Lrc<SourceMap>,
     bundle: Option<Lrc<FluentBundle>>,
     fallback_bundle: LazyFallbackBundle,
+    tool_version: &'static str,
 ) -> Box<DynEmitter> {
     let macro_backtrace = sopts.unstable_opts.macro_backtrace;
     let track_diagnostics = sopts.unstable_opts.track_diagnostics;
@@ -1378,6 +1403,11 @@ fn default_emitter(
                 sopts.unstable_opts.ignore_directory_in_diagnostics_source_blocks.clone(),
             ),
         ),
+        config::ErrorOutputType::Sarif => Box::new(
+            SarifEmitter::stderr(Some(registry), source_map, bundle, fallback_bundle)
+                .tool_version(Some(tool_version))
+                .ui_testing(sopts.unstable_opts.ui_testing),
+        ),
     }
 }
 
@@ -1440,7 +1470,14 @@ pub fn build_session(
         fluent_resources,
         sopts.unstable_opts.translate_directionality_markers,
     );
-    let emitter = default_emitter(&sopts, registry, source_map.clone(), bundle, fallback_bundle);
+    let emitter = default_emitter(
+        &sopts,
+        registry,
+        source_map.clone(),
+        bundle,
+        fallback_bundle,
+        cfg_version,
+    );
 
     let mut span_diagnostic = rustc_errors::Handler::with_emitter(emitter)
         .with_flags(sopts.unstable_opts.diagnostic_handler_flags(can_emit_warnings));
@@ -1670,6 +1707,9 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         if sess.opts.unstable_opts.virtual_function_elimination {
             sess.emit_err(errors::UnstableVirtualFunctionElimination);
         }
+        if sess.opts.unstable_opts.print_unreachable_items {
+            sess.emit_err(errors::PrintUnreachableItemsRequiresLto);
+        }
     }
 
     if sess.opts.unstable_opts.stack_protector != StackProtector::None {
@@ -1813,6 +1853,15 @@ fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
             false,
             TerminalUrl::No,
         )),
+        config::ErrorOutputType::Sarif => Box::new(
+            SarifEmitter::stderr(
+                None,
+                Lrc::new(SourceMap::new(FilePathMapping::empty())),
+                None,
+                fallback_bundle,
+            )
+            .early(true),
+        ),
     };
     emitter
 }
@@ -603,6 +603,7 @@
         coroutines,
         cosf32,
         cosf64,
+        cost,
         count,
         coverage,
         coverage_attribute,
@@ -1023,6 +1024,8 @@
         minnumf32,
         minnumf64,
         mips_target_feature,
+        mir_inline,
+        mir_inline_attribute,
         miri,
         misc,
         mmx_reg,
@@ -1379,6 +1382,7 @@
         rustc_error,
         rustc_evaluate_where_clauses,
         rustc_expected_cgu_reuse,
+        rustc_expensive_on,
         rustc_has_incoherent_inherent_impls,
         rustc_hidden_type_of_opaques,
         rustc_host,
@@ -1430,6 +1434,7 @@
         rustc_then_this_would_need,
         rustc_trivial_field_reads,
         rustc_unsafe_specialization_marker,
+        rustc_unsupported_on,
         rustc_variance,
         rustc_variance_of_opaques,
         rustdoc,
@@ -1742,6 +1747,7 @@
         va_start,
         val,
         validity,
+        value_barrier,
         values,
         var,
         variant_count,
@@ -1777,6 +1783,7 @@
         wrapping_rem_euclid,
         wrapping_sub,
         wreg,
+        write,
         write_bytes,
         write_macro,
         write_str,
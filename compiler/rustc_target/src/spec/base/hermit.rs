@@ -1,4 +1,4 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, PanicStrategy, TargetOptions, TlsModel};
+use crate::spec::{cvs, Cc, LinkerFlavor, Lld, PanicStrategy, TargetOptions, TlsModel};
 
 pub fn opts() -> TargetOptions {
     TargetOptions {
@@ -10,6 +10,7 @@ pub fn opts() -> TargetOptions {
         static_position_independent_executables: true,
         has_thread_local: true,
         panic_strategy: PanicStrategy::Abort,
+        unsupported_std_facilities: Some(cvs!["process"]),
         ..Default::default()
     }
 }
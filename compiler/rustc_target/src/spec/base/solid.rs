@@ -1,4 +1,4 @@
-use crate::spec::{FramePointer, TargetOptions};
+use crate::spec::{cvs, FramePointer, TargetOptions};
 
 pub fn opts(kernel: &str) -> TargetOptions {
     TargetOptions {
@@ -7,6 +7,7 @@ pub fn opts(kernel: &str) -> TargetOptions {
         executables: false,
         frame_pointer: FramePointer::NonLeaf,
         has_thread_local: true,
+        unsupported_std_facilities: Some(cvs!["args", "process"]),
         ..Default::default()
     }
 }
@@ -9,7 +9,7 @@
 // the timer-interrupt. Device-drivers are required to use polling-based models. Furthermore, all
 // code runs in the same environment, no process separation is supported.
 
-use crate::spec::{base, LinkerFlavor, Lld, PanicStrategy, StackProbeType, TargetOptions};
+use crate::spec::{base, cvs, LinkerFlavor, Lld, PanicStrategy, StackProbeType, TargetOptions};
 
 pub fn opts() -> TargetOptions {
     let mut base = base::msvc::opts();
@@ -47,6 +47,7 @@ pub fn opts() -> TargetOptions {
         singlethread: true,
         linker: Some("rust-lld".into()),
         entry_name: "efi_main".into(),
+        unsupported_std_facilities: Some(cvs!["fs", "net", "process", "thread", "time"]),
         ..base
     }
 }
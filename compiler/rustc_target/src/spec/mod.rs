@@ -2198,6 +2198,39 @@ pub struct TargetOptions {
 
     /// Forces the use of emulated TLS (__emutls_get_address)
     pub force_emulated_tls: bool,
+
+    /// Whether referencing an API that the standard library marks as unsupported on this
+    /// target's `os` (with `#[rustc_unsupported_on]`) from a final binary is an error, rather
+    /// than failing at runtime.
+    pub deny_unsupported_apis: bool,
+
+    /// Parts of the standard library, out of [`STD_FACILITIES`], that are only stubs on this
+    /// target: they exist so that code compiles, but return errors or panic when used.
+    ///
+    /// `None` means that this is not known, see [`TargetOptions::std_facility_status`].
+    pub unsupported_std_facilities: Option<StaticCow<[StaticCow<str>]>>,
+}
+
+/// The parts of the standard library that a target can leave unimplemented, as reported by
+/// `--print std-capabilities`.
+pub const STD_FACILITIES: &[&str] = &["args", "env", "fs", "net", "process", "thread", "time"];
+
+/// Whether a target implements one of the [`STD_FACILITIES`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StdFacilityStatus {
+    Implemented,
+    Unsupported,
+    Unknown,
+}
+
+impl StdFacilityStatus {
+    pub fn desc(self) -> &'static str {
+        match self {
+            StdFacilityStatus::Implemented => "implemented",
+            StdFacilityStatus::Unsupported => "unsupported",
+            StdFacilityStatus::Unknown => "unknown",
+        }
+    }
 }
 
 /// Add arguments for the given flavor and also for its "twin" flavors
@@ -2296,6 +2329,31 @@ fn update_to_cli(&mut self) {
                 .collect();
         }
     }
+
+    /// Returns whether the target has a standard library at all. Targets without an operating
+    /// system only have `core` and `alloc`.
+    pub fn has_std(&self) -> bool {
+        self.os != "none"
+    }
+
+    /// Returns whether the target's `std` implements `facility`, one of [`STD_FACILITIES`].
+    ///
+    /// Targets that don't list their unsupported facilities are assumed to implement all of them
+    /// if they are in the `unix` or `windows` family, because `std`'s `sys::unix` and
+    /// `sys::windows` do; Unix targets that stub out part of `sys::unix` list that part. For all
+    /// other targets, the answer is unknown.
+    pub fn std_facility_status(&self, facility: &str) -> StdFacilityStatus {
+        match &self.unsupported_std_facilities {
+            Some(unsupported) if unsupported.iter().any(|u| u == facility) => {
+                StdFacilityStatus::Unsupported
+            }
+            Some(_) => StdFacilityStatus::Implemented,
+            None if self.families.iter().any(|f| f == "unix" || f == "windows") => {
+                StdFacilityStatus::Implemented
+            }
+            None => StdFacilityStatus::Unknown,
+        }
+    }
 }
 
 impl Default for TargetOptions {
@@ -2418,6 +2476,8 @@ fn default() -> TargetOptions {
             entry_abi: Conv::C,
             supports_xray: false,
             force_emulated_tls: false,
+            deny_unsupported_apis: false,
+            unsupported_std_facilities: None,
         }
     }
 }
@@ -3122,6 +3182,8 @@ macro_rules! key {
         key!(entry_abi, Conv)?;
         key!(supports_xray, bool);
         key!(force_emulated_tls, bool);
+        key!(deny_unsupported_apis, bool);
+        key!(unsupported_std_facilities, opt_list);
 
         if base.is_builtin {
             // This can cause unfortunate ICEs later down the line.
@@ -3378,6 +3440,8 @@ macro_rules! target_option_val {
         target_option_val!(entry_abi);
         target_option_val!(supports_xray);
         target_option_val!(force_emulated_tls);
+        target_option_val!(deny_unsupported_apis);
+        target_option_val!(unsupported_std_facilities);
 
         if let Some(abi) = self.default_adjusted_cabi {
             d.insert("default-adjusted-cabi".into(), Abi::name(abi).to_json());
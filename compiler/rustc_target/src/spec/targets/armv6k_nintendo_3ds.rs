@@ -30,6 +30,7 @@ pub fn target() -> Target {
             exe_suffix: ".elf".into(),
             no_default_libraries: false,
             has_thread_local: true,
+            unsupported_std_facilities: Some(cvs!["process"]),
             ..Default::default()
         },
     }
@@ -1,4 +1,4 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel};
+use crate::spec::{cvs, Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel};
 use crate::spec::{Target, TargetOptions};
 
 pub fn target() -> Target {
@@ -30,6 +30,8 @@ pub fn target() -> Target {
             emit_debug_gdb_scripts: false,
             eh_frame_header: false,
             singlethread: true,
+            deny_unsupported_apis: true,
+            unsupported_std_facilities: Some(cvs!["fs", "net", "process", "thread", "time"]),
             ..Default::default()
         },
     }
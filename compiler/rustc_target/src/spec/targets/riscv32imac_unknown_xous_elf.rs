@@ -1,4 +1,4 @@
-use crate::spec::{Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel, Target, TargetOptions};
+use crate::spec::{cvs, Cc, LinkerFlavor, Lld, PanicStrategy, RelocModel, Target, TargetOptions};
 
 pub fn target() -> Target {
     Target {
@@ -16,6 +16,7 @@ pub fn target() -> Target {
             features: "+m,+a,+c".into(),
             panic_strategy: PanicStrategy::Abort,
             relocation_model: RelocModel::Static,
+            unsupported_std_facilities: Some(cvs!["args", "env", "fs", "net", "process"]),
             ..Default::default()
         },
     }
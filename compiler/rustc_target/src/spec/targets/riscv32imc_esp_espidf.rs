@@ -28,6 +28,7 @@ pub fn target() -> Target {
             relocation_model: RelocModel::Static,
             emit_debug_gdb_scripts: false,
             eh_frame_header: false,
+            unsupported_std_facilities: Some(cvs!["process"]),
             ..Default::default()
         },
     }
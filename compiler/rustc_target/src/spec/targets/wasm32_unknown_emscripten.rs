@@ -23,6 +23,7 @@ pub fn target() -> Target {
         panic_strategy: PanicStrategy::Unwind,
         no_default_libraries: false,
         families: cvs!["unix", "wasm"],
+        unsupported_std_facilities: Some(cvs!["process"]),
         ..base::wasm::options()
     };
     Target {
@@ -11,7 +11,7 @@
 //! Group nowadays at <https://github.com/rustwasm>.
 
 use crate::spec::abi::Abi;
-use crate::spec::{base, Cc, LinkerFlavor, Target};
+use crate::spec::{base, cvs, Cc, LinkerFlavor, Target};
 
 pub fn target() -> Target {
     let mut options = base::wasm::options();
@@ -26,6 +26,8 @@ pub fn target() -> Target {
     // clang's ABI. This means that, in the limit, you can't merge C and Rust
     // code on this target due to this ABI mismatch.
     options.default_adjusted_cabi = Some(Abi::Wasm);
+    options.unsupported_std_facilities =
+        Some(cvs!["args", "env", "fs", "net", "process", "thread", "time"]);
 
     options.add_pre_link_args(
         LinkerFlavor::WasmLld(Cc::No),
@@ -7,11 +7,13 @@
 //! the standard library is available, most of it returns an error immediately
 //! (e.g. trying to create a TCP stream or something like that).
 
-use crate::spec::{base, Cc, LinkerFlavor, Target};
+use crate::spec::{base, cvs, Cc, LinkerFlavor, Target};
 
 pub fn target() -> Target {
     let mut options = base::wasm::options();
     options.os = "unknown".into();
+    options.unsupported_std_facilities =
+        Some(cvs!["args", "env", "fs", "net", "process", "thread", "time"]);
 
     options.add_pre_link_args(
         LinkerFlavor::WasmLld(Cc::No),
@@ -70,6 +70,7 @@ pub fn target() -> Target {
         pre_link_args,
         override_export_symbols: Some(EXPORT_SYMBOLS.iter().cloned().map(Cow::from).collect()),
         relax_elf_relocations: true,
+        unsupported_std_facilities: Some(cvs!["fs", "process"]),
         ..Default::default()
     };
     Target {
@@ -18,6 +18,9 @@ fn check_consistency(&self) {
         if self.is_like_msvc {
             assert!(self.is_like_windows);
         }
+        for facility in self.unsupported_std_facilities.iter().flat_map(|list| list.iter()) {
+            assert!(STD_FACILITIES.contains(&&**facility), "unknown std facility `{facility}`");
+        }
 
         // Check that default linker flavor is compatible with some other key properties.
         assert_eq!(self.is_like_osx, matches!(self.linker_flavor, LinkerFlavor::Darwin(..)));
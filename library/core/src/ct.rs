@@ -0,0 +1,185 @@
+//! Constant-time operations.
+//!
+//! The functions in this module take the same time no matter what values
+//! they are given, other than the lengths of slices. This is what code
+//! handling secrets, e.g. keys or MACs, needs to avoid leaking them through
+//! timing side channels.
+//!
+//! Writing branch-free code is not enough for that by itself, as the optimizer
+//! is free to turn it back into branches, and frequently does so for the mask
+//! tricks such code relies on. The functions here pass their intermediate
+//! values through [`value_barrier`](crate::intrinsics::value_barrier), which
+//! the compiler guarantees to treat as opaque, so no branch on the operands is
+//! ever emitted. In contrast, [`hint::black_box`](crate::hint::black_box) is
+//! only a best effort and must not be relied on for this.
+//!
+//! The results are returned as plain `bool`s and integers. Branching on them
+//! afterwards is of course not constant-time anymore, so they should only be
+//! branched on once it is fine to reveal them.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(core_ct)]
+//! use core::ct;
+//!
+//! fn check_tag(expected: &[u8; 16], actual: &[u8; 16]) -> bool {
+//!     // Unlike `==`, this does not stop at the first differing byte.
+//!     ct::ct_eq_bytes(expected, actual)
+//! }
+//!
+//! assert!(check_tag(&[7; 16], &[7; 16]));
+//! assert!(!check_tag(&[7; 16], &[8; 16]));
+//! assert_eq!(ct::ct_select(true, 1u32, 2), 1);
+//! ```
+
+use crate::intrinsics::value_barrier;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An integer type that supports the constant-time operations of this module.
+///
+/// This trait is sealed and implemented for all primitive integer types.
+#[unstable(feature = "core_ct", issue = "none")]
+pub trait ConstantTimeInt: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn ct_eq(self, other: Self) -> bool;
+
+    #[doc(hidden)]
+    fn ct_lt(self, other: Self) -> bool;
+
+    #[doc(hidden)]
+    fn ct_select(condition: bool, a: Self, b: Self) -> Self;
+}
+
+macro_rules! impl_constant_time_int {
+    ($($T:ty => $U:ty, $bias:expr;)*) => {$(
+        impl private::Sealed for $T {}
+
+        #[unstable(feature = "core_ct", issue = "none")]
+        impl ConstantTimeInt for $T {
+            #[inline]
+            fn ct_eq(self, other: Self) -> bool {
+                let diff = (self ^ other) as $U;
+                // The top bit of `diff | -diff` is set iff `diff` is not zero.
+                let nonzero = (diff | diff.wrapping_neg()) >> (<$U>::BITS - 1);
+                value_barrier(nonzero ^ 1) != 0
+            }
+
+            #[inline]
+            fn ct_lt(self, other: Self) -> bool {
+                // Biasing by the sign bit maps signed values to unsigned ones in
+                // an order-preserving way.
+                let a = self as $U ^ $bias;
+                let b = other as $U ^ $bias;
+                // The top bit of this is the borrow out of `a - b`.
+                let borrow = (!a & b) | (!(a ^ b) & a.wrapping_sub(b));
+                value_barrier(borrow >> (<$U>::BITS - 1)) != 0
+            }
+
+            #[inline]
+            fn ct_select(condition: bool, a: Self, b: Self) -> Self {
+                let mask = value_barrier((condition as $U).wrapping_neg()) as $T;
+                b ^ (mask & (a ^ b))
+            }
+        }
+    )*};
+}
+
+impl_constant_time_int! {
+    u8 => u8, 0;
+    u16 => u16, 0;
+    u32 => u32, 0;
+    u64 => u64, 0;
+    u128 => u128, 0;
+    usize => usize, 0;
+    i8 => u8, 1 << (u8::BITS - 1);
+    i16 => u16, 1 << (u16::BITS - 1);
+    i32 => u32, 1 << (u32::BITS - 1);
+    i64 => u64, 1 << (u64::BITS - 1);
+    i128 => u128, 1 << (u128::BITS - 1);
+    isize => usize, 1 << (usize::BITS - 1);
+}
+
+/// Returns `a == b`, in constant time.
+#[unstable(feature = "core_ct", issue = "none")]
+#[must_use]
+#[inline]
+pub fn ct_eq<T: ConstantTimeInt>(a: T, b: T) -> bool {
+    a.ct_eq(b)
+}
+
+/// Returns `a < b`, in constant time.
+#[unstable(feature = "core_ct", issue = "none")]
+#[must_use]
+#[inline]
+pub fn ct_lt<T: ConstantTimeInt>(a: T, b: T) -> bool {
+    a.ct_lt(b)
+}
+
+/// Returns `a` if `condition` is `true` and `b` otherwise, in constant time.
+#[unstable(feature = "core_ct", issue = "none")]
+#[must_use]
+#[inline]
+pub fn ct_select<T: ConstantTimeInt>(condition: bool, a: T, b: T) -> T {
+    T::ct_select(condition, a, b)
+}
+
+/// Returns `a == b`, in time that only depends on the lengths of the slices.
+///
+/// Slices of different lengths are never equal.
+#[unstable(feature = "core_ct", issue = "none")]
+#[must_use]
+#[inline]
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0;
+    for (x, y) in a.iter().zip(b) {
+        // Without the barrier, the loop could exit early once `diff` is known.
+        diff = value_barrier(diff | (x ^ y));
+    }
+    diff.ct_eq(0)
+}
+
+/// Returns `a < b`, in time that only depends on the lengths of the slices.
+///
+/// The slices are compared lexicographically, like `<[u8]>::lt` does, which
+/// for slices of the same length is the same as comparing them as big-endian
+/// unsigned integers.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[unstable(feature = "core_ct", issue = "none")]
+#[must_use]
+#[inline]
+pub fn ct_lt_bytes(a: &[u8], b: &[u8]) -> bool {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let mut lt = 0u8;
+    // Going from the least significant byte up, every differing byte
+    // overrides the result so far.
+    for (&x, &y) in a.iter().zip(b).rev() {
+        lt = u8::ct_select(x.ct_eq(y), lt, x.ct_lt(y) as u8);
+    }
+    lt != 0
+}
+
+/// Copies `a` into `dest` if `condition` is `true`, and `b` otherwise, in
+/// time that only depends on the lengths of the slices.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[unstable(feature = "core_ct", issue = "none")]
+#[inline]
+pub fn ct_select_bytes(condition: bool, a: &[u8], b: &[u8], dest: &mut [u8]) {
+    assert!(a.len() == b.len() && a.len() == dest.len(), "slices must have the same length");
+    let mask = value_barrier((condition as u8).wrapping_neg());
+    for ((d, x), y) in dest.iter_mut().zip(a).zip(b) {
+        *d = y ^ (mask & (x ^ y));
+    }
+}
@@ -2416,6 +2416,21 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[rustc_nounwind]
     pub fn black_box<T>(dummy: T) -> T;
 
+    /// Returns `value` unchanged, while preventing the optimizer from learning
+    /// anything about it.
+    ///
+    /// Unlike [`black_box`], this is a guarantee rather than a best effort: code
+    /// generation treats the result as an arbitrary value, so e.g. a mask
+    /// derived from a comparison is never turned back into a branch. It only
+    /// accepts integer types.
+    ///
+    /// See [`core::ct`](crate::ct) for the constant-time operations built on
+    /// this.
+    #[rustc_const_unstable(feature = "core_ct", issue = "none")]
+    #[rustc_safe_intrinsic]
+    #[rustc_nounwind]
+    pub fn value_barrier<T: Copy>(value: T) -> T;
+
     /// `ptr` must point to a vtable.
     /// The intrinsic will return the size stored in that vtable.
     #[rustc_nounwind]
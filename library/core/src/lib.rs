@@ -112,6 +112,7 @@
 //
 // Library features:
 // tidy-alphabetical-start
+#![feature(bigint_helper_methods)]
 #![feature(char_indices_offset)]
 #![feature(const_align_of_val)]
 #![feature(const_align_of_val_raw)]
@@ -368,6 +369,8 @@ pub mod assert_matches {
 pub mod async_iter;
 pub mod cell;
 pub mod char;
+#[unstable(feature = "core_ct", issue = "none")]
+pub mod ct;
 pub mod ffi;
 #[unstable(feature = "core_io_borrowed_buf", issue = "117693")]
 pub mod io;
@@ -0,0 +1,383 @@
+//! Fixed-width multi-precision unsigned integers.
+//!
+//! [`Uint<LIMBS>`] is an unsigned integer made of `LIMBS` 32-bit limbs, stored
+//! least significant limb first. The arithmetic is built on the single-word
+//! helpers [`u32::carrying_add`], [`u32::borrowing_sub`] and
+//! [`u32::carrying_mul`], so on targets with a 32-bit multiplier (e.g. RV32IM)
+//! every limb product lowers to a `mul`/`mulhu` pair without any calls into
+//! compiler-rt.
+//!
+//! Apart from the `checked_*` methods and comparisons, none of the operations
+//! branch on the values of the operands. All of them are `const fn`.
+
+#![unstable(feature = "core_bigint", issue = "none")]
+
+use crate::cmp::Ordering;
+
+/// An unsigned integer of `LIMBS` 32-bit limbs, i.e. `32 * LIMBS` bits.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_bigint)]
+/// use core::num::bigint::Uint;
+///
+/// let a = Uint::<2>::from_limbs([u32::MAX, 0]);
+/// let (sum, carry) = a.carrying_add(Uint::ONE, false);
+/// assert_eq!(sum.to_limbs(), [0, 1]);
+/// assert!(!carry);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Uint<const LIMBS: usize> {
+    limbs: [u32; LIMBS],
+}
+
+/// Computes `a * b + acc + carry`, returning the low and the high word.
+///
+/// This cannot overflow: `(2^32 - 1)^2 + 2 * (2^32 - 1) = 2^64 - 1`.
+#[inline]
+const fn mul_add(a: u32, b: u32, acc: u32, carry: u32) -> (u32, u32) {
+    let (lo, hi) = a.carrying_mul(b, carry);
+    let (lo, c) = lo.overflowing_add(acc);
+    (lo, hi + c as u32)
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// The number of bits in this integer type.
+    pub const BITS: u32 = LIMBS as u32 * u32::BITS;
+
+    /// The value zero.
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+
+    /// The value one.
+    pub const ONE: Self = Self::from_u32(1);
+
+    /// The largest value that can be represented, `2^BITS - 1`.
+    pub const MAX: Self = Self { limbs: [u32::MAX; LIMBS] };
+
+    /// Creates an integer from its limbs, least significant first.
+    #[inline]
+    pub const fn from_limbs(limbs: [u32; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    /// Creates an integer from a single word.
+    ///
+    /// For `Uint<0>` the value is truncated to zero.
+    #[inline]
+    pub const fn from_u32(value: u32) -> Self {
+        let mut limbs = [0; LIMBS];
+        if LIMBS > 0 {
+            limbs[0] = value;
+        }
+        Self { limbs }
+    }
+
+    /// Returns the limbs of this integer, least significant first.
+    #[inline]
+    pub const fn as_limbs(&self) -> &[u32; LIMBS] {
+        &self.limbs
+    }
+
+    /// Returns the limbs of this integer, least significant first.
+    #[inline]
+    pub const fn to_limbs(self) -> [u32; LIMBS] {
+        self.limbs
+    }
+
+    /// Returns `true` if this integer is zero.
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            acc |= self.limbs[i];
+            i += 1;
+        }
+        acc == 0
+    }
+
+    /// Calculates `self + rhs + carry`, returning the sum and whether the
+    /// addition overflowed.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut carry = carry;
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], carry) = self.limbs[i].carrying_add(rhs.limbs[i], carry);
+            i += 1;
+        }
+        (Self { limbs }, carry)
+    }
+
+    /// Calculates `self - rhs - borrow`, returning the difference and whether
+    /// the subtraction underflowed.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = borrow;
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], borrow) = self.limbs[i].borrowing_sub(rhs.limbs[i], borrow);
+            i += 1;
+        }
+        (Self { limbs }, borrow)
+    }
+
+    /// Calculates `self + rhs`, returning the sum and whether the addition
+    /// overflowed.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        self.carrying_add(rhs, false)
+    }
+
+    /// Calculates `self - rhs`, returning the difference and whether the
+    /// subtraction underflowed.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.borrowing_sub(rhs, false)
+    }
+
+    /// Calculates `self + rhs` modulo `2^BITS`.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.carrying_add(rhs, false).0
+    }
+
+    /// Calculates `self - rhs` modulo `2^BITS`.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.borrowing_sub(rhs, false).0
+    }
+
+    /// Calculates `self + rhs`, returning `None` if the addition overflowed.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.carrying_add(rhs, false) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    /// Calculates `self - rhs`, returning `None` if the subtraction
+    /// underflowed.
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.borrowing_sub(rhs, false) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    /// Calculates the full product `self * rhs` using schoolbook
+    /// multiplication, returning the low and the high half.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(core_bigint)]
+    /// use core::num::bigint::Uint;
+    ///
+    /// let (lo, hi) = Uint::<2>::MAX.widening_mul(Uint::MAX);
+    /// assert_eq!(lo, Uint::ONE);
+    /// assert_eq!(hi.to_limbs(), [u32::MAX - 1, u32::MAX]);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let mut lo = [0; LIMBS];
+        let mut hi = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let mut carry = 0;
+            let mut j = 0;
+            // Columns `i..LIMBS` of this row land in the low half...
+            while i + j < LIMBS {
+                (lo[i + j], carry) = mul_add(self.limbs[i], rhs.limbs[j], lo[i + j], carry);
+                j += 1;
+            }
+            // ...and the rest in the high half.
+            while j < LIMBS {
+                let k = i + j - LIMBS;
+                (hi[k], carry) = mul_add(self.limbs[i], rhs.limbs[j], hi[k], carry);
+                j += 1;
+            }
+            hi[i] = carry;
+            i += 1;
+        }
+        (Self { limbs: lo }, Self { limbs: hi })
+    }
+
+    /// Calculates `self * rhs` modulo `2^BITS`.
+    ///
+    /// This only computes the low half of the product, which takes about half
+    /// the limb multiplications of [`widening_mul`](Self::widening_mul).
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        let mut lo = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let mut carry = 0;
+            let mut j = 0;
+            while i + j < LIMBS {
+                (lo[i + j], carry) = mul_add(self.limbs[i], rhs.limbs[j], lo[i + j], carry);
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { limbs: lo }
+    }
+
+    /// Computes the Montgomery constant `-modulus^-1 mod 2^32` needed by
+    /// [`montgomery_mul`](Self::montgomery_mul).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is even, as it then has no inverse modulo `2^32`.
+    #[must_use]
+    #[inline]
+    pub const fn montgomery_neg_inv(modulus: &Self) -> u32 {
+        assert!(LIMBS > 0 && modulus.limbs[0] & 1 == 1, "the modulus must be odd");
+        let m = modulus.limbs[0];
+        // Newton's iteration doubles the number of correct low bits each step,
+        // starting from the 3 bits that `m * m == 1 mod 8` gives for odd `m`.
+        let mut inv = m;
+        let mut i = 0;
+        while i < 4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(m.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    }
+
+    /// Calculates the Montgomery product `self * rhs * 2^-BITS mod modulus`.
+    ///
+    /// `neg_inv` must be [`Uint::montgomery_neg_inv(modulus)`](Self::montgomery_neg_inv),
+    /// and both `self` and `rhs` must be less than `modulus`; the result then
+    /// is as well. Otherwise the result is unspecified, but this does not
+    /// panic.
+    ///
+    /// This interleaves the multiplication and the reduction one limb at a
+    /// time (the CIOS method), so the intermediate value never takes more than
+    /// `LIMBS + 2` limbs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(core_bigint)]
+    /// use core::num::bigint::Uint;
+    ///
+    /// // 2^64 - 59 is prime, and R = 2^64 is 59 modulo it, so R^2 is 59^2.
+    /// let p = Uint::<2>::from_limbs([u32::MAX - 58, u32::MAX]);
+    /// let neg_inv = Uint::montgomery_neg_inv(&p);
+    /// let r2 = Uint::from_u32(59 * 59);
+    ///
+    /// // Multiplying by R^2 converts into Montgomery form, `a * R`, and the
+    /// // product of two values in Montgomery form stays in Montgomery form.
+    /// let a = Uint::from_u32(6).montgomery_mul(r2, &p, neg_inv);
+    /// let b = Uint::from_u32(7).montgomery_mul(r2, &p, neg_inv);
+    /// let c = Uint::from_u32(42).montgomery_mul(r2, &p, neg_inv);
+    /// assert_eq!(a.montgomery_mul(b, &p, neg_inv), c);
+    ///
+    /// // Multiplying by one converts back.
+    /// assert_eq!(c.montgomery_mul(Uint::ONE, &p, neg_inv), Uint::from_u32(42));
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn montgomery_mul(self, rhs: Self, modulus: &Self, neg_inv: u32) -> Self {
+        let mut t = [0; LIMBS];
+        // The limb above `t`. It is at most one between iterations.
+        let mut t_hi: u32 = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            // t += self * rhs[i], with the overflow going to `t_hi` and `t_hi2`.
+            let mut carry = 0;
+            let mut j = 0;
+            while j < LIMBS {
+                (t[j], carry) = mul_add(self.limbs[j], rhs.limbs[i], t[j], carry);
+                j += 1;
+            }
+            let (sum, c) = t_hi.overflowing_add(carry);
+            t_hi = sum;
+            let t_hi2 = c as u32;
+
+            // t = (t + m * modulus) / 2^32, where `m` is chosen to make the
+            // lowest limb zero.
+            let m = t[0].wrapping_mul(neg_inv);
+            let (_, mut carry) = mul_add(m, modulus.limbs[0], t[0], 0);
+            let mut j = 1;
+            while j < LIMBS {
+                (t[j - 1], carry) = mul_add(m, modulus.limbs[j], t[j], carry);
+                j += 1;
+            }
+            let (sum, c) = t_hi.overflowing_add(carry);
+            t[LIMBS - 1] = sum;
+            t_hi = t_hi2 + c as u32;
+            i += 1;
+        }
+
+        // Now `t < 2 * modulus`, so at most one subtraction is needed. It is
+        // done unconditionally and the result selected with a mask.
+        let t = Self { limbs: t };
+        let (reduced, borrow) = t.borrowing_sub(*modulus, false);
+        let (_, borrow) = t_hi.borrowing_sub(0, borrow);
+        let mask = (borrow as u32).wrapping_neg();
+        let mut limbs = [0; LIMBS];
+        let mut j = 0;
+        while j < LIMBS {
+            limbs[j] = (t.limbs[j] & mask) | (reduced.limbs[j] & !mask);
+            j += 1;
+        }
+        Self { limbs }
+    }
+}
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> From<u32> for Uint<LIMBS> {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::from_u32(value)
+    }
+}
@@ -47,6 +47,8 @@ macro_rules! unlikely {
 mod saturating;
 mod wrapping;
 
+pub mod bigint;
+
 #[stable(feature = "saturating_int_impl", since = "1.74.0")]
 pub use saturating::Saturating;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -0,0 +1,76 @@
+use core::ct::{ct_eq, ct_eq_bytes, ct_lt, ct_lt_bytes, ct_select, ct_select_bytes};
+
+macro_rules! test_int {
+    ($($name:ident: $T:ty;)*) => {$(
+        #[test]
+        fn $name() {
+            let values = [<$T>::MIN, <$T>::MIN + 1, 0, 1, 2, 0x55, <$T>::MAX - 1, <$T>::MAX];
+            for a in values {
+                for b in values {
+                    assert_eq!(ct_eq(a, b), a == b, "{a} == {b}");
+                    assert_eq!(ct_lt(a, b), a < b, "{a} < {b}");
+                    assert_eq!(ct_select(true, a, b), a);
+                    assert_eq!(ct_select(false, a, b), b);
+                }
+            }
+        }
+    )*};
+}
+
+test_int! {
+    test_u8: u8;
+    test_u16: u16;
+    test_u32: u32;
+    test_u64: u64;
+    test_u128: u128;
+    test_usize: usize;
+    test_i8: i8;
+    test_i16: i16;
+    test_i32: i32;
+    test_i64: i64;
+    test_i128: i128;
+    test_isize: isize;
+}
+
+#[test]
+fn test_eq_bytes() {
+    assert!(ct_eq_bytes(b"", b""));
+    assert!(ct_eq_bytes(b"zkvm", b"zkvm"));
+    assert!(!ct_eq_bytes(b"zkvm", b"zkvM"));
+    assert!(!ct_eq_bytes(b"zkvm", b"Zkvm"));
+    assert!(!ct_eq_bytes(b"zkvm", b"zkv"));
+}
+
+#[test]
+fn test_lt_bytes() {
+    let values: [&[u8; 3]; 6] =
+        [b"\0\0\0", b"\0\0\x01", b"\0\xff\0", b"\x01\0\0", b"ab\xff", b"\xff\xff\xff"];
+    for a in values {
+        for b in values {
+            assert_eq!(ct_lt_bytes(a, b), a < b, "{a:?} < {b:?}");
+        }
+    }
+    assert!(!ct_lt_bytes(b"", b""));
+}
+
+#[test]
+#[should_panic]
+fn test_lt_bytes_different_lengths() {
+    let _ = ct_lt_bytes(b"ab", b"abc");
+}
+
+#[test]
+fn test_select_bytes() {
+    let mut dest = [0; 4];
+    ct_select_bytes(true, b"left", b"rght", &mut dest);
+    assert_eq!(&dest, b"left");
+    ct_select_bytes(false, b"left", b"rght", &mut dest);
+    assert_eq!(&dest, b"rght");
+}
+
+#[test]
+#[should_panic]
+fn test_select_bytes_different_lengths() {
+    let mut dest = [0; 3];
+    ct_select_bytes(true, b"left", b"rght", &mut dest);
+}
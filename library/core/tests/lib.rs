@@ -22,6 +22,8 @@
 #![feature(const_trait_impl)]
 #![feature(const_likely)]
 #![feature(const_location_fields)]
+#![feature(core_bigint)]
+#![feature(core_ct)]
 #![feature(core_intrinsics)]
 #![feature(core_io_borrowed_buf)]
 #![feature(core_private_bignum)]
@@ -132,6 +134,7 @@
 mod cmp;
 mod const_ptr;
 mod convert;
+mod ct;
 mod fmt;
 mod future;
 mod hash;
@@ -0,0 +1,137 @@
+use core::num::bigint::Uint;
+
+fn from_u128(value: u128) -> Uint<4> {
+    let limb = |shift: u32| (value >> shift) as u32;
+    Uint::from_limbs([limb(0), limb(32), limb(64), limb(96)])
+}
+
+fn to_u128(value: Uint<4>) -> u128 {
+    value.to_limbs().iter().rev().fold(0, |acc, &limb| acc << 32 | limb as u128)
+}
+
+fn from_u64(value: u64) -> Uint<2> {
+    Uint::from_limbs([value as u32, (value >> 32) as u32])
+}
+
+fn to_u64(value: Uint<2>) -> u64 {
+    let [lo, hi] = value.to_limbs();
+    (hi as u64) << 32 | lo as u64
+}
+
+const VALUES: &[u128] = &[
+    0,
+    1,
+    2,
+    u32::MAX as u128,
+    1 << 32,
+    u64::MAX as u128,
+    1 << 64,
+    0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+    0xffff_ffff_0000_0000_ffff_ffff_0000_0000,
+    u128::MAX - 1,
+    u128::MAX,
+];
+
+#[test]
+fn test_constants() {
+    assert_eq!(Uint::<4>::BITS, 128);
+    assert_eq!(to_u128(Uint::ZERO), 0);
+    assert_eq!(to_u128(Uint::ONE), 1);
+    assert_eq!(to_u128(Uint::MAX), u128::MAX);
+    assert_eq!(Uint::<4>::default(), Uint::ZERO);
+    assert!(Uint::<4>::ZERO.is_zero());
+    assert!(!Uint::<4>::ONE.is_zero());
+    assert_eq!(Uint::<0>::from_u32(5), Uint::ZERO);
+}
+
+#[test]
+fn test_add_sub() {
+    for &a in VALUES {
+        for &b in VALUES {
+            let (x, y) = (from_u128(a), from_u128(b));
+            let (sum, carry) = a.overflowing_add(b);
+            assert_eq!(x.overflowing_add(y), (from_u128(sum), carry));
+            assert_eq!(x.checked_add(y), a.checked_add(b).map(from_u128));
+            let (difference, borrow) = a.overflowing_sub(b);
+            assert_eq!(x.overflowing_sub(y), (from_u128(difference), borrow));
+            assert_eq!(x.checked_sub(y), a.checked_sub(b).map(from_u128));
+        }
+    }
+}
+
+#[test]
+fn test_carrying_add_borrowing_sub() {
+    assert_eq!(Uint::<4>::MAX.carrying_add(Uint::ZERO, true), (Uint::ZERO, true));
+    assert_eq!(Uint::<4>::ZERO.carrying_add(Uint::ZERO, true), (Uint::ONE, false));
+    assert_eq!(Uint::<4>::ZERO.borrowing_sub(Uint::ZERO, true), (Uint::MAX, true));
+    assert_eq!(Uint::<4>::ONE.borrowing_sub(Uint::ZERO, true), (Uint::ZERO, false));
+}
+
+#[test]
+fn test_mul() {
+    for &a in VALUES {
+        for &b in VALUES {
+            let (x, y) = (from_u128(a), from_u128(b));
+            assert_eq!(to_u128(x.wrapping_mul(y)), a.wrapping_mul(b));
+            assert_eq!(to_u128(x.widening_mul(y).0), a.wrapping_mul(b));
+
+            let (a, b) = (a as u64, b as u64);
+            let (lo, hi) = from_u64(a).widening_mul(from_u64(b));
+            let product = a as u128 * b as u128;
+            assert_eq!((to_u64(lo), to_u64(hi)), (product as u64, (product >> 64) as u64));
+        }
+    }
+    let (lo, hi) = Uint::<4>::MAX.widening_mul(Uint::MAX);
+    assert_eq!((to_u128(lo), to_u128(hi)), (1, u128::MAX - 1));
+}
+
+#[test]
+fn test_montgomery() {
+    // A prime and an arbitrary odd composite, both with the top limb set.
+    for modulus in [u64::MAX - 58, 0xc000_0000_0000_0001] {
+        let p = from_u64(modulus);
+        let neg_inv = Uint::montgomery_neg_inv(&p);
+        assert_eq!((modulus as u32).wrapping_mul(neg_inv.wrapping_neg()), 1);
+        // R = 2^64 modulo p.
+        let r = (1u128 << 64) % modulus as u128;
+        for &a in VALUES {
+            for &b in VALUES {
+                let (a, b) = (a as u64 % modulus, b as u64 % modulus);
+                let c = to_u64(from_u64(a).montgomery_mul(from_u64(b), &p, neg_inv));
+                assert!(c < modulus);
+                // c = a * b / R, i.e. c * R = a * b.
+                let expected = a as u128 * b as u128 % modulus as u128;
+                assert_eq!(c as u128 * r % modulus as u128, expected);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_montgomery_even_modulus() {
+    let _ = Uint::montgomery_neg_inv(&from_u64(1 << 40));
+}
+
+#[test]
+fn test_const() {
+    const P: Uint<2> = Uint::from_limbs([u32::MAX - 58, u32::MAX]);
+    const NEG_INV: u32 = Uint::montgomery_neg_inv(&P);
+    const PRODUCT: (Uint<2>, Uint<2>) = P.widening_mul(P);
+    const MONT: Uint<2> = Uint::from_u32(59 * 59).montgomery_mul(Uint::ONE, &P, NEG_INV);
+    let p = u64::MAX - 58;
+    let product = p as u128 * p as u128;
+    assert_eq!(to_u64(PRODUCT.0), product as u64);
+    assert_eq!(to_u64(PRODUCT.1), (product >> 64) as u64);
+    // R^2 / R = R = 59.
+    assert_eq!(MONT, Uint::from_u32(59));
+}
+
+#[test]
+fn test_ord() {
+    for &a in VALUES {
+        for &b in VALUES {
+            assert_eq!(from_u128(a).cmp(&from_u128(b)), a.cmp(&b));
+        }
+    }
+}
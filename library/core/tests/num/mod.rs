@@ -26,6 +26,7 @@
 mod u64;
 mod u8;
 
+mod bigint;
 mod bignum;
 
 mod const_from;
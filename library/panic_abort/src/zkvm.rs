@@ -4,6 +4,12 @@
 // Forward the abort message to zkVM's sys_panic. This is implemented by RISC Zero's
 // platform crate which exposes system calls specifically for the zkVM.
 pub(crate) unsafe fn zkvm_set_abort_message(payload: &mut dyn PanicPayload) {
+    // Let std report what it needs to before the guest halts.
+    extern "C" {
+        fn __rust_zkvm_halt();
+    }
+    __rust_zkvm_halt();
+
     let payload = payload.get();
     let msg = match payload.downcast_ref::<&'static str>() {
         Some(msg) => msg.as_bytes(),
@@ -232,6 +232,13 @@ impl<K, V> HashMap<K, V, RandomState> {
     #[inline]
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_expensive_on(
+            target_os = "zkvm",
+            reason = "every `RandomState` requests new keys from the host"
+        )
+    )]
     pub fn new() -> HashMap<K, V, RandomState> {
         Default::default()
     }
@@ -251,6 +258,13 @@ pub fn new() -> HashMap<K, V, RandomState> {
     #[inline]
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_expensive_on(
+            target_os = "zkvm",
+            reason = "every `RandomState` requests new keys from the host"
+        )
+    )]
     pub fn with_capacity(capacity: usize) -> HashMap<K, V, RandomState> {
         HashMap::with_capacity_and_hasher(capacity, Default::default())
     }
@@ -3110,6 +3124,13 @@ impl RandomState {
     // rand
     #[must_use]
     #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_expensive_on(
+            target_os = "zkvm",
+            reason = "every `RandomState` requests new keys from the host"
+        )
+    )]
     pub fn new() -> RandomState {
         // Historically this function did not cache keys from the OS and instead
         // simply always called `rand::thread_rng().gen()` twice. In #31356 it
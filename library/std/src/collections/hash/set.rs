@@ -123,6 +123,13 @@ impl<T> HashSet<T, RandomState> {
     #[inline]
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_expensive_on(
+            target_os = "zkvm",
+            reason = "every `RandomState` requests new keys from the host"
+        )
+    )]
     pub fn new() -> HashSet<T, RandomState> {
         Default::default()
     }
@@ -143,6 +150,13 @@ pub fn new() -> HashSet<T, RandomState> {
     #[inline]
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_expensive_on(
+            target_os = "zkvm",
+            reason = "every `RandomState` requests new keys from the host"
+        )
+    )]
     pub fn with_capacity(capacity: usize) -> HashSet<T, RandomState> {
         HashSet::with_capacity_and_hasher(capacity, Default::default())
     }
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::hash::Hasher;
+use crate::sys::sha256;
+
+/// Size of a SHA-256 block in bytes.
+const BLOCK_LEN: usize = 64;
+
+/// The initial hash value, see FIPS 180-4, section 5.3.3.
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// An incremental SHA-256 hasher.
+///
+/// Data is fed in with [`update`] and the 32-byte digest is obtained with
+/// [`finalize`], or in one go with [`Sha256::digest`]. The result is the same
+/// on every target, but on targets that accelerate SHA-256, such as the
+/// zkVMs, the compression function runs on the accelerator instead of in
+/// software. This lets portable code hash data cheaply inside a zkVM guest
+/// without depending on a target-specific crate.
+///
+/// `Sha256` also implements [`Hasher`], so that any [`Hash`] value can be fed
+/// into it. [`Hasher::finish`] returns the first eight bytes of the digest as a
+/// big-endian integer. Note that the byte representation produced by [`Hash`]
+/// implementations is not specified and may differ between targets and Rust
+/// versions, so digests of byte slices should be preferred for anything that
+/// is stored or compared across programs.
+///
+/// [`update`]: Sha256::update
+/// [`finalize`]: Sha256::finalize
+/// [`Hash`]: crate::hash::Hash
+///
+/// # Examples
+///
+/// ```
+/// #![feature(hash_sha256)]
+/// use std::hash::Sha256;
+///
+/// let mut hasher = Sha256::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// let digest = hasher.finalize();
+///
+/// assert_eq!(digest, Sha256::digest(b"hello world"));
+/// assert_eq!(digest[..4], [0xb9, 0x4d, 0x27, 0xb9]);
+/// ```
+#[unstable(feature = "hash_sha256", issue = "none")]
+#[derive(Clone)]
+pub struct Sha256 {
+    state: [u32; 8],
+    /// Input that does not fill a whole block yet.
+    buffer: [u8; BLOCK_LEN],
+    buffered: usize,
+    /// Total length of the input in bytes.
+    len: u64,
+}
+
+impl Sha256 {
+    /// Creates a new hasher.
+    #[unstable(feature = "hash_sha256", issue = "none")]
+    #[must_use]
+    pub const fn new() -> Sha256 {
+        Sha256 { state: INITIAL_STATE, buffer: [0; BLOCK_LEN], buffered: 0, len: 0 }
+    }
+
+    /// Computes the SHA-256 digest of `data`.
+    #[unstable(feature = "hash_sha256", issue = "none")]
+    #[must_use]
+    pub fn digest(data: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Feeds `data` into the hasher.
+    #[unstable(feature = "hash_sha256", issue = "none")]
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.wrapping_add(data.len() as u64);
+
+        if self.buffered > 0 {
+            let n = data.len().min(BLOCK_LEN - self.buffered);
+            self.buffer[self.buffered..][..n].copy_from_slice(&data[..n]);
+            self.buffered += n;
+            data = &data[n..];
+            if self.buffered < BLOCK_LEN {
+                return;
+            }
+            sha256::compress(&mut self.state, &[self.buffer]);
+            self.buffered = 0;
+        }
+
+        let (blocks, rest) = data.as_chunks::<BLOCK_LEN>();
+        if !blocks.is_empty() {
+            sha256::compress(&mut self.state, blocks);
+        }
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    /// Consumes the hasher and returns the digest of all the data fed into it.
+    #[unstable(feature = "hash_sha256", issue = "none")]
+    #[must_use]
+    pub fn finalize(mut self) -> [u8; 32] {
+        // Append a single set bit, pad with zeros up to the last 8 bytes of a
+        // block, and end with the length of the input in bits.
+        let bit_len = self.len.wrapping_mul(8);
+        self.buffer[self.buffered] = 0x80;
+        self.buffer[self.buffered + 1..].fill(0);
+        if self.buffered + 1 > BLOCK_LEN - 8 {
+            sha256::compress(&mut self.state, &[self.buffer]);
+            self.buffer.fill(0);
+        }
+        self.buffer[BLOCK_LEN - 8..].copy_from_slice(&bit_len.to_be_bytes());
+        sha256::compress(&mut self.state, &[self.buffer]);
+
+        let mut digest = [0; 32];
+        for (bytes, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
+#[unstable(feature = "hash_sha256", issue = "none")]
+impl Default for Sha256 {
+    fn default() -> Sha256 {
+        Sha256::new()
+    }
+}
+
+#[unstable(feature = "hash_sha256", issue = "none")]
+impl fmt::Debug for Sha256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sha256").finish_non_exhaustive()
+    }
+}
+
+#[unstable(feature = "hash_sha256", issue = "none")]
+impl Hasher for Sha256 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let digest = self.clone().finalize();
+        u64::from_be_bytes(digest[..8].try_into().unwrap())
+    }
+}
@@ -0,0 +1,73 @@
+use super::Sha256;
+use crate::hash::Hasher;
+
+fn hex(digest: [u8; 32]) -> String {
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[test]
+fn known_digests() {
+    // Test vectors from FIPS 180-4 and NIST's SHA-256 examples.
+    assert_eq!(
+        hex(Sha256::digest(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex(Sha256::digest(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        hex(Sha256::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+    assert_eq!(
+        hex(Sha256::digest(&[b'a'; 1_000_000])),
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    );
+}
+
+#[test]
+fn incremental_matches_one_shot() {
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 + 3) as u8).collect();
+    let expected = Sha256::digest(&data);
+    // Split sizes around the block and padding boundaries.
+    for split in [0, 1, 55, 56, 63, 64, 65, 127, 128, 500, 999, 1000] {
+        let mut hasher = Sha256::new();
+        hasher.update(&data[..split]);
+        hasher.update(&data[split..]);
+        assert_eq!(hasher.finalize(), expected, "split at {split}");
+    }
+
+    let mut hasher = Sha256::new();
+    for byte in &data {
+        hasher.update(&[*byte]);
+    }
+    assert_eq!(hasher.finalize(), expected);
+}
+
+#[test]
+fn padding_boundaries() {
+    // The padding of 55 bytes of input still fits into the last block, from 56
+    // bytes on it needs an extra block.
+    let expected = [
+        (55, "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"),
+        (56, "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"),
+        (63, "7d3e74a05d7db15bce4ad9ec0658ea98e3f06eeecf16b4c6fff2da457ddc2f34"),
+        (64, "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"),
+    ];
+    for (len, digest) in expected {
+        assert_eq!(hex(Sha256::digest(&vec![b'a'; len])), digest, "length {len}");
+    }
+}
+
+#[test]
+fn hasher_finish() {
+    let mut hasher = Sha256::new();
+    hasher.write(b"abc");
+    assert_eq!(hasher.finish(), 0xba7816bf8f01cfea);
+    // `finish` does not consume or reset the hasher.
+    assert_eq!(hasher.finish(), 0xba7816bf8f01cfea);
+    hasher.write(b"d");
+    let digest = Sha256::digest(b"abcd");
+    assert_eq!(hasher.finish(), u64::from_be_bytes(digest[..8].try_into().unwrap()));
+}
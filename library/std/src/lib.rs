@@ -339,6 +339,7 @@
 #![feature(ptr_as_uninit)]
 #![feature(raw_os_nonzero)]
 #![feature(round_ties_even)]
+#![feature(slice_as_chunks)]
 #![feature(slice_internals)]
 #![feature(slice_ptr_get)]
 #![feature(std_internals)]
@@ -491,12 +492,12 @@
 pub use core::cmp;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::convert;
+#[unstable(feature = "core_ct", issue = "none")]
+pub use core::ct;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::default;
 #[stable(feature = "futures_api", since = "1.36.0")]
 pub use core::future;
-#[stable(feature = "rust1", since = "1.0.0")]
-pub use core::hash;
 #[stable(feature = "core_hint", since = "1.27.0")]
 pub use core::hint;
 #[stable(feature = "i128", since = "1.26.0")]
@@ -565,8 +566,17 @@
 pub mod env;
 pub mod error;
 pub mod ffi;
+#[cfg_attr(
+    not(bootstrap),
+    rustc_unsupported_on(target_os = "zkvm", reason = "there is no file system inside the zkVM")
+)]
 pub mod fs;
+pub mod hash;
 pub mod io;
+#[cfg_attr(
+    not(bootstrap),
+    rustc_unsupported_on(target_os = "zkvm", reason = "there is no network access inside the zkVM")
+)]
 pub mod net;
 pub mod num;
 pub mod os;
@@ -162,6 +162,8 @@ pub mod windows {}
 pub(crate) mod watchos;
 #[cfg(target_os = "xous")]
 pub mod xous;
+#[cfg(target_os = "zkvm")]
+pub mod zkvm;
 
 #[cfg(any(unix, target_os = "wasi", doc))]
 pub mod fd;
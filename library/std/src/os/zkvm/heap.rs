@@ -0,0 +1,61 @@
+use crate::sys::alloc;
+
+/// Statistics about the allocations made through [`System`] so far, as
+/// returned by [`heap_stats`].
+///
+/// The allocator of the zkVM never frees memory, so the heap only grows: its
+/// high-water mark is the peak heap usage of the guest.
+///
+/// [`System`]: crate::alloc::System
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeapStats {
+    allocations: usize,
+    allocated_bytes: usize,
+    high_water_mark: usize,
+}
+
+impl HeapStats {
+    /// The number of allocations, including the ones made while growing or
+    /// reallocating a collection.
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+
+    /// The total number of bytes requested by all allocations.
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated_bytes
+    }
+
+    /// The number of bytes between the start of the first allocation and the
+    /// end of the last one. Unlike [`allocated_bytes`](Self::allocated_bytes),
+    /// this includes the padding required by the alignment of allocations.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+}
+
+/// Returns statistics about the allocations made so far.
+///
+/// Counting allocations costs cycles, so it is only done when `std` is built
+/// with the `zkvm-heap-stats` feature, e.g. with
+/// `cargo build -Zbuild-std -Zbuild-std-features=zkvm-heap-stats`. Otherwise,
+/// this returns `None`.
+///
+/// With the feature, the statistics are also sent to the host's log (see
+/// [`log`](super::log)) when the program exits.
+///
+/// # Examples
+///
+/// ```ignore (only available on zkVM targets)
+/// #![feature(zkvm_std)]
+/// use std::os::zkvm;
+///
+/// let input = vec![0u8; 1024];
+/// if let Some(stats) = zkvm::heap_stats() {
+///     zkvm::log!("{} bytes of heap used", stats.high_water_mark());
+/// }
+/// ```
+pub fn heap_stats() -> Option<HeapStats> {
+    let (allocations, allocated_bytes, high_water_mark) = alloc::stats()?;
+    Some(HeapStats { allocations, allocated_bytes, high_water_mark })
+}
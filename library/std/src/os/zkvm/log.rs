@@ -0,0 +1,56 @@
+use crate::fmt;
+use crate::sys::stdio;
+
+/// Sends a message to the host's log.
+///
+/// Unlike the standard output and standard error streams, the log is not part
+/// of the journal or of any other proven output of the guest: it is only meant
+/// for debugging. Messages are passed to the host as they are, without a
+/// trailing newline.
+///
+/// This function always sends the message. Use the [`log!`] macro instead to
+/// have the message and its formatting compiled out of release proofs.
+///
+/// [`log!`]: crate::os::zkvm::log!
+pub fn log(msg: &str) {
+    stdio::log(msg)
+}
+
+/// Formats `args` and sends the result to the host's log, see [`log`].
+///
+/// This is an implementation detail of [`log!`], which should be used instead.
+///
+/// [`log!`]: crate::os::zkvm::log!
+#[doc(hidden)]
+pub fn log_fmt(args: fmt::Arguments<'_>) {
+    match args.as_str() {
+        Some(msg) => log(msg),
+        None => log(&fmt::format(args)),
+    }
+}
+
+/// Formats a message like [`eprintln!`] and sends it to the host's log, see
+/// [`log`].
+///
+/// Logging is only enabled when the calling crate is compiled with debug
+/// assertions, or with `--cfg zkvm_log`. Otherwise the message is never
+/// formatted nor sent, and the whole invocation is removed during compilation,
+/// so it costs no cycles in release proofs. The arguments are still
+/// type-checked in either case.
+///
+/// # Examples
+///
+/// ```ignore (only available on zkVM targets)
+/// #![feature(zkvm_std)]
+/// use std::os::zkvm;
+///
+/// let input: u32 = 42;
+/// zkvm::log!("processing input {input}");
+/// ```
+#[allow_internal_unstable(zkvm_std)]
+#[rustc_macro_transparency = "semitransparent"]
+pub macro log($($arg:tt)*) {
+    if cfg!(any(debug_assertions, zkvm_log)) {
+        $crate::os::zkvm::log_fmt($crate::format_args!($($arg)*));
+    }
+}
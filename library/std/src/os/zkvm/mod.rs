@@ -0,0 +1,10 @@
+//! Platform-specific extensions to `std` for zkVM targets.
+
+#![unstable(feature = "zkvm_std", issue = "none")]
+#![doc(cfg(target_os = "zkvm"))]
+
+mod heap;
+mod log;
+
+pub use self::heap::{heap_stats, HeapStats};
+pub use self::log::{log, log_fmt};
@@ -535,6 +535,13 @@ pub struct Command {
 #[unstable(feature = "sealed", issue = "none")]
 impl crate::sealed::Sealed for Command {}
 
+#[cfg_attr(
+    not(bootstrap),
+    rustc_unsupported_on(
+        target_os = "zkvm",
+        reason = "processes cannot be spawned inside the zkVM"
+    )
+)]
 impl Command {
     /// Constructs a new `Command` for launching the program at
     /// path `program`, with the following default configuration:
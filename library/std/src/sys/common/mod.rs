@@ -11,6 +11,7 @@
 #![allow(dead_code)]
 
 pub mod alloc;
+pub mod sha256;
 pub mod small_c_string;
 #[allow(unused_imports)]
 pub mod thread_local;
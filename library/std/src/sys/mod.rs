@@ -62,6 +62,15 @@
     }
 }
 
+cfg_if::cfg_if! {
+    // Targets with an accelerated SHA-256 implementation.
+    if #[cfg(target_os = "zkvm")] {
+        pub use self::zkvm::sha256;
+    } else {
+        pub use self::common::sha256;
+    }
+}
+
 cfg_if::cfg_if! {
     // Fuchsia components default to full backtrace.
     if #[cfg(target_os = "fuchsia")] {
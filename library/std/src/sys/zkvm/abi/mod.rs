@@ -0,0 +1,61 @@
+//! The syscall ABI of the zkVM platform.
+//!
+//! Every zkVM vendor provides the same set of syscalls, but may name them and
+//! number the standard file descriptors differently. The vendor's ABI is
+//! described by a table passed to `zkvm_abi!`, and is selected by
+//! `target_vendor`. The rest of `sys::zkvm` only uses the items the table
+//! generates, but it relies on the signatures being the same for every vendor,
+//! including whether a length is in bytes or in words. A vendor whose syscalls
+//! match them only needs a target spec and a table; any other difference has
+//! to be handled where the syscall is used. The tables are kept here rather
+//! than in `libc` because `std` is the only user of most of these syscalls.
+
+#![allow(dead_code)]
+
+/// Declares the syscall ABI of a zkVM vendor.
+///
+/// The table gives the number of words in a SHA-256 digest, the file
+/// descriptors of the standard streams and the journal, and for every syscall
+/// `std` uses its Rust name, its argument and return types, and the symbol the
+/// vendor's platform crate exports it under. All syscalls are required, the
+/// Rust names and signatures are fixed, and only the symbols and file
+/// descriptors vary between vendors. The argument names say which lengths are
+/// counted in words (`nwords`, `words`) and which in bytes (`nbytes`, `len`).
+macro_rules! zkvm_abi {
+    (
+        digest_words: $digest_words:expr;
+
+        fileno {
+            $($fileno:ident = $fileno_value:expr;)*
+        }
+
+        syscalls {
+            $(
+                fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)?) $(-> $ret:ty)? = $symbol:literal;
+            )*
+        }
+    ) => {
+        pub const DIGEST_WORDS: usize = $digest_words;
+
+        /// Standard IO file descriptors for use with sys_read and sys_write.
+        pub mod fileno {
+            $(pub const $fileno: u32 = $fileno_value;)*
+        }
+
+        extern "C" {
+            $(
+                #[link_name = $symbol]
+                pub fn $name($($arg: $arg_ty),*) $(-> $ret)?;
+            )*
+        }
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(target_vendor = "succinct")] {
+        mod succinct;
+        pub use self::succinct::*;
+    } else {
+        compile_error!("no syscall table for this zkVM vendor, add one to `sys/zkvm/abi`");
+    }
+}
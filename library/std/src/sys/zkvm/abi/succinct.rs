@@ -0,0 +1,53 @@
+//! Syscall table for the symbols exported by succinct-zkvm-platform.
+
+// Included here so we don't have to depend on succinct-zkvm-platform.
+
+zkvm_abi! {
+    digest_words: 8;
+
+    fileno {
+        STDIN = 0;
+        STDOUT = 1;
+        STDERR = 2;
+        JOURNAL = 3;
+    }
+
+    syscalls {
+        fn sys_halt() = "sys_halt";
+        fn sys_output(output_id: u32, output_value: u32) = "sys_output";
+        fn sys_sha_compress(
+            out_state: *mut [u32; DIGEST_WORDS],
+            in_state: *const [u32; DIGEST_WORDS],
+            block1_ptr: *const [u32; DIGEST_WORDS],
+            block2_ptr: *const [u32; DIGEST_WORDS],
+        ) = "sys_sha_compress";
+        fn sys_sha_buffer(
+            out_state: *mut [u32; DIGEST_WORDS],
+            in_state: *const [u32; DIGEST_WORDS],
+            buf: *const u8,
+            count: u32,
+        ) = "sys_sha_buffer";
+        fn sys_rand(recv_buf: *mut u8, nbytes: usize) = "sys_rand";
+        fn sys_panic(msg_ptr: *const u8, len: usize) -> ! = "sys_panic";
+        fn sys_log(msg_ptr: *const u8, len: usize) = "sys_log";
+        fn sys_cycle_count() -> usize = "sys_cycle_count";
+        fn sys_read(fd: u32, recv_buf: *mut u8, nrequested: usize) -> usize = "sys_read";
+        fn sys_write(fd: u32, write_buf: *const u8, nbytes: usize) = "sys_write";
+        fn sys_getenv(
+            recv_buf: *mut u32,
+            words: usize,
+            varname: *const u8,
+            varname_len: usize,
+        ) -> usize = "sys_getenv";
+        fn sys_argc() -> usize = "sys_argc";
+        fn sys_argv(
+            out_words: *mut u32,
+            out_nwords: usize,
+            arg_index: usize,
+        ) -> usize = "sys_argv";
+
+        // Allocate memory from global HEAP.
+        fn sys_alloc_words(nwords: usize) -> *mut u32 = "sys_alloc_words";
+        fn sys_alloc_aligned(nbytes: usize, align: usize) -> *mut u8 = "sys_alloc_aligned";
+    }
+}
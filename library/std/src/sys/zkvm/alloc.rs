@@ -5,7 +5,12 @@
 unsafe impl GlobalAlloc for System {
     #[inline]
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        abi::sys_alloc_aligned(layout.size(), layout.align())
+        let ptr = abi::sys_alloc_aligned(layout.size(), layout.align());
+        #[cfg(feature = "zkvm-heap-stats")]
+        if !ptr.is_null() {
+            stats::record(ptr, layout.size());
+        }
+        ptr
     }
 
     #[inline]
@@ -13,3 +18,38 @@ unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
         // this allocator never deallocates memory
     }
 }
+
+/// Returns the number of allocations, the number of bytes requested by them,
+/// and the size of the part of the heap they span, or `None` if `std` was
+/// built without the `zkvm-heap-stats` feature.
+pub fn stats() -> Option<(usize, usize, usize)> {
+    #[cfg(feature = "zkvm-heap-stats")]
+    return Some(stats::get());
+    #[cfg(not(feature = "zkvm-heap-stats"))]
+    return None;
+}
+
+#[cfg(feature = "zkvm-heap-stats")]
+mod stats {
+    use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    static BYTES: AtomicUsize = AtomicUsize::new(0);
+    // Since nothing is ever freed, the heap is the range between the lowest
+    // start and the highest end of any allocation.
+    static LOWEST: AtomicUsize = AtomicUsize::new(usize::MAX);
+    static HIGHEST: AtomicUsize = AtomicUsize::new(0);
+
+    pub fn record(ptr: *mut u8, size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size, Relaxed);
+        LOWEST.fetch_min(ptr.addr(), Relaxed);
+        HIGHEST.fetch_max(ptr.addr() + size, Relaxed);
+    }
+
+    pub fn get() -> (usize, usize, usize) {
+        let allocations = ALLOCATIONS.load(Relaxed);
+        let high_water_mark = HIGHEST.load(Relaxed).saturating_sub(LOWEST.load(Relaxed));
+        (allocations, BYTES.load(Relaxed), high_water_mark)
+    }
+}
@@ -57,13 +57,14 @@ fn next(&mut self) -> Option<OsString> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.count, Some(self.count))
+        let len = self.len();
+        (len, Some(len))
     }
 }
 
 impl ExactSizeIterator for Args {
     fn len(&self) -> usize {
-        self.count
+        self.count - self.i_forward - self.i_back
     }
 }
 
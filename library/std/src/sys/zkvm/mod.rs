@@ -1,7 +1,8 @@
-//! System bindings for the succinct zkvm platform
+//! System bindings for zkvm platforms
 //!
 //! This module contains the facade (aka platform-specific) implementations of
-//! OS level functionality for zkvm.
+//! OS level functionality for zkvm. They are shared by all zkVM vendors and
+//! only reach the platform through the syscall table of the vendor in `abi`.
 //!
 //! This is all super highly experimental and not actually intended for
 //! wide/production use yet, it's still all in the experimental category. This
@@ -32,6 +33,7 @@
 pub mod pipe;
 #[path = "../unsupported/process.rs"]
 pub mod process;
+pub mod sha256;
 pub mod stdio;
 pub mod thread_local_key;
 #[path = "../unsupported/time.rs"]
@@ -47,6 +49,9 @@
 
 mod abi;
 
+#[cfg(test)]
+mod tests;
+
 use crate::io as std_io;
 
 pub mod memchr {
@@ -59,7 +64,38 @@ pub unsafe fn init(_argc: isize, _argv: *const *const u8, _sigpipe: u8) {}
 
 // SAFETY: must be called only once during runtime cleanup.
 // NOTE: this is not guaranteed to run, for example when the program aborts.
-pub unsafe fn cleanup() {}
+pub unsafe fn cleanup() {
+    log_heap_stats();
+}
+
+/// Logs the heap statistics, if `std` collects them, right before the guest
+/// halts. Every way out of the guest that goes through `std` gets here:
+/// returning from `main` and `process::exit` through `cleanup`, aborts
+/// through `abort_internal`, and panics through `__rust_zkvm_halt`. Only the
+/// first call logs anything.
+fn log_heap_stats() {
+    use crate::sync::atomic::{AtomicBool, Ordering::Relaxed};
+
+    static LOGGED: AtomicBool = AtomicBool::new(false);
+    if LOGGED.swap(true, Relaxed) {
+        return;
+    }
+    if let Some((allocations, bytes, high_water_mark)) = alloc::stats() {
+        // Formatting allocates too, but after the statistics were taken.
+        stdio::log(&crate::fmt::format(format_args!(
+            "heap: {allocations} allocations, {bytes} bytes allocated, \
+             high-water mark {high_water_mark} bytes"
+        )));
+    }
+}
+
+// This function is needed by the panic runtime, which calls it before
+// handing the panic message to `sys_panic`.
+#[cfg(not(test))]
+#[no_mangle]
+pub extern "C" fn __rust_zkvm_halt() {
+    log_heap_stats();
+}
 
 pub fn unsupported<T>() -> std_io::Result<T> {
     Err(unsupported_err())
@@ -81,6 +117,7 @@ pub fn decode_error_kind(_code: i32) -> crate::io::ErrorKind {
 }
 
 pub fn abort_internal() -> ! {
+    log_heap_stats();
     core::intrinsics::abort();
 }
 
@@ -131,7 +131,7 @@ pub fn home_dir() -> Option<PathBuf> {
 }
 
 pub fn exit(_code: i32) -> ! {
-    crate::intrinsics::abort()
+    super::abort_internal()
 }
 
 pub fn getpid() -> u32 {
@@ -0,0 +1,43 @@
+//! SHA-256 compression using the zkVM's accelerator.
+
+use super::abi::{self, DIGEST_WORDS};
+
+/// Updates `state` with each of `blocks` in turn.
+pub fn compress(state: &mut [u32; DIGEST_WORDS], blocks: &[[u8; 64]]) {
+    if blocks.is_empty() {
+        return;
+    }
+
+    // The accelerator works on the state as it is laid out in the digest, i.e.
+    // with each word in big-endian byte order.
+    let mut in_state = state.map(u32::to_be);
+    let mut out_state = [0; DIGEST_WORDS];
+
+    if blocks.as_ptr().cast::<u32>().is_aligned() {
+        // SAFETY: the input is word-aligned and holds `blocks.len()` blocks.
+        unsafe {
+            abi::sys_sha_buffer(
+                &mut out_state,
+                &in_state,
+                blocks.as_ptr().cast(),
+                blocks.len() as u32,
+            );
+        }
+    } else {
+        // The accelerator reads whole words, so copy each block to an aligned
+        // buffer first. The words keep the byte order of the input.
+        for block in blocks {
+            let mut halves = [[0u32; DIGEST_WORDS]; 2];
+            for (word, bytes) in halves.iter_mut().flatten().zip(block.chunks_exact(4)) {
+                *word = u32::from_ne_bytes(bytes.try_into().unwrap());
+            }
+            // SAFETY: all pointers refer to valid, aligned arrays of `DIGEST_WORDS` words.
+            unsafe {
+                abi::sys_sha_compress(&mut out_state, &in_state, &halves[0], &halves[1]);
+            }
+            in_state = out_state;
+        }
+    }
+
+    *state = out_state.map(u32::from_be);
+}
@@ -0,0 +1,103 @@
+use super::abi;
+use super::args::args;
+use super::os::getenv;
+use super::stdio::{Stderr, Stdout};
+use crate::alloc::{GlobalAlloc, Layout, System};
+use crate::ffi::{OsStr, OsString};
+use crate::io::Write;
+
+const MISSING_VAR: &str = "RUST_ZKVM_TEST_VARIABLE_THAT_IS_NOT_SET";
+
+#[test]
+fn args_forward_and_back() {
+    let forward: Vec<OsString> = args().collect();
+    let back: Vec<OsString> = args().rev().collect();
+    assert_eq!(forward.len(), args().len());
+    // The test harness always gets at least the name of the program.
+    assert!(!forward.is_empty());
+    assert!(forward.iter().eq(back.iter().rev()));
+}
+
+#[test]
+fn args_len_after_partial_iteration() {
+    let mut args = args();
+    let count = args.len();
+    assert_eq!(args.size_hint(), (count, Some(count)));
+    args.next().unwrap();
+    assert_eq!(args.len(), count - 1);
+    if args.next_back().is_some() {
+        assert_eq!(args.len(), count - 2);
+    }
+    args.by_ref().for_each(drop);
+    assert_eq!(args.len(), 0);
+    assert_eq!(args.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn args_cross_in_the_middle() {
+    let expected: Vec<OsString> = args().collect();
+    let mut args = args();
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    // Alternate between the ends until `i_forward` and `i_back` meet, so that every argument is
+    // yielded exactly once.
+    loop {
+        match args.next() {
+            Some(arg) => front.push(arg),
+            None => break,
+        }
+        match args.next_back() {
+            Some(arg) => back.push(arg),
+            None => break,
+        }
+    }
+    assert_eq!(args.next(), None);
+    assert_eq!(args.next_back(), None);
+    front.extend(back.into_iter().rev());
+    assert_eq!(front, expected);
+}
+
+#[test]
+fn getenv_missing() {
+    let varname = MISSING_VAR.as_bytes();
+    let nbytes =
+        unsafe { abi::sys_getenv(crate::ptr::null_mut(), 0, varname.as_ptr(), varname.len()) };
+    assert_eq!(nbytes, usize::MAX);
+    assert_eq!(getenv(OsStr::new(MISSING_VAR)), None);
+}
+
+#[test]
+fn stdio_writes() {
+    // Writes go straight to the host and always succeed in full.
+    assert_eq!(Stdout::new().write(b"").unwrap(), 0);
+    assert_eq!(Stdout::new().write(b"zkvm stdout\n").unwrap(), 12);
+    assert_eq!(Stderr::new().write(b"zkvm stderr\n").unwrap(), 12);
+    Stdout::new().flush().unwrap();
+    Stderr::new().flush().unwrap();
+}
+
+#[test]
+fn alloc_alignment() {
+    for align in (0..13).map(|shift| 1 << shift) {
+        for size in [1, 3, 4, 7, 64, 1000] {
+            let layout = Layout::from_size_align(size, align).unwrap();
+            let ptr = unsafe { System.alloc(layout) };
+            assert!(!ptr.is_null());
+            assert_eq!(ptr.addr() % align, 0, "size {size}, align {align}");
+            // The whole allocation must be usable.
+            unsafe {
+                ptr.write_bytes(0xa5, size);
+                assert_eq!(*ptr.add(size - 1), 0xa5);
+                System.dealloc(ptr, layout);
+            }
+        }
+    }
+}
+
+#[test]
+fn alloc_does_not_overlap() {
+    let layout = Layout::from_size_align(24, 8).unwrap();
+    let a = unsafe { System.alloc(layout) };
+    let b = unsafe { System.alloc(layout) };
+    assert!(a.addr() + 24 <= b.addr() || b.addr() + 24 <= a.addr());
+}
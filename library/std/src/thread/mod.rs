@@ -380,6 +380,13 @@ pub fn stack_size(mut self, size: usize) -> Builder {
     /// handler.join().unwrap();
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_unsupported_on(
+            target_os = "zkvm",
+            reason = "threads cannot be spawned inside the zkVM"
+        )
+    )]
     pub fn spawn<F, T>(self, f: F) -> io::Result<JoinHandle<T>>
     where
         F: FnOnce() -> T,
@@ -679,6 +686,10 @@ fn drop(&mut self) {
 /// [`join`]: JoinHandle::join
 /// [`Err`]: crate::result::Result::Err
 #[stable(feature = "rust1", since = "1.0.0")]
+#[cfg_attr(
+    not(bootstrap),
+    rustc_unsupported_on(target_os = "zkvm", reason = "threads cannot be spawned inside the zkVM")
+)]
 pub fn spawn<F, T>(f: F) -> JoinHandle<T>
 where
     F: FnOnce() -> T,
@@ -870,6 +881,10 @@ pub fn sleep_ms(ms: u32) {
 /// assert!(now.elapsed() >= ten_millis);
 /// ```
 #[stable(feature = "thread_sleep", since = "1.4.0")]
+#[cfg_attr(
+    not(bootstrap),
+    rustc_unsupported_on(target_os = "zkvm", reason = "there is no clock inside the zkVM")
+)]
 pub fn sleep(dur: Duration) {
     imp::Thread::sleep(dur)
 }
@@ -186,6 +186,13 @@ impl<'scope, 'env> Scope<'scope, 'env> {
     ///
     /// [`join`]: ScopedJoinHandle::join
     #[stable(feature = "scoped_threads", since = "1.63.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_unsupported_on(
+            target_os = "zkvm",
+            reason = "threads cannot be spawned inside the zkVM"
+        )
+    )]
     pub fn spawn<F, T>(&'scope self, f: F) -> ScopedJoinHandle<'scope, T>
     where
         F: FnOnce() -> T + Send + 'scope,
@@ -243,6 +250,13 @@ impl Builder {
     /// assert_eq!(x, a.len());
     /// ```
     #[stable(feature = "scoped_threads", since = "1.63.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_unsupported_on(
+            target_os = "zkvm",
+            reason = "threads cannot be spawned inside the zkVM"
+        )
+    )]
     pub fn spawn_scoped<'scope, 'env, F, T>(
         self,
         scope: &'scope Scope<'scope, 'env>,
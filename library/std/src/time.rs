@@ -281,6 +281,10 @@ impl Instant {
     /// ```
     #[must_use]
     #[stable(feature = "time2", since = "1.8.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_unsupported_on(target_os = "zkvm", reason = "there is no clock inside the zkVM")
+    )]
     pub fn now() -> Instant {
         Instant(time::Instant::now())
     }
@@ -509,6 +513,10 @@ impl SystemTime {
     /// ```
     #[must_use]
     #[stable(feature = "time2", since = "1.8.0")]
+    #[cfg_attr(
+        not(bootstrap),
+        rustc_unsupported_on(target_os = "zkvm", reason = "there is no clock inside the zkVM")
+    )]
     pub fn now() -> SystemTime {
         SystemTime(time::SystemTime::now())
     }
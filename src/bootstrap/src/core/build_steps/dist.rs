@@ -1519,6 +1519,18 @@ macro_rules! add_component {
         // before rust-std in the list below.
         tarballs.push(builder.ensure(Rustc { compiler: builder.compiler(stage, target) }));
         tarballs.push(builder.ensure(Std { compiler, target }).expect("missing std"));
+        for &std_target in &builder.config.dist_include_std_targets {
+            if std_target != target {
+                let compiler = builder.compiler_for(stage, self.host, std_target);
+                let std = builder.ensure(Std { compiler, target: std_target });
+                tarballs.push(std.expect("missing std"));
+            }
+        }
+        // `rust-src` is not part of the combined installer by default, since rustup installs it
+        // separately.
+        if builder.config.tools.as_ref().map_or(false, |tools| tools.contains("src")) {
+            tarballs.push(builder.ensure(Src));
+        }
 
         if target.ends_with("windows-gnu") {
             tarballs.push(builder.ensure(Mingw { host: target }).expect("missing mingw"));
@@ -22,6 +22,7 @@ pub enum Profile {
     Library,
     Tools,
     Dist,
+    Zkvm,
     None,
 }
 
@@ -46,7 +47,7 @@ fn include_path(&self, src_path: &Path) -> PathBuf {
     pub fn all() -> impl Iterator<Item = Self> {
         use Profile::*;
         // N.B. these are ordered by how they are displayed, not alphabetically
-        [Library, Compiler, Codegen, Tools, Dist, None].iter().copied()
+        [Library, Compiler, Codegen, Tools, Dist, Zkvm, None].iter().copied()
     }
 
     pub fn purpose(&self) -> String {
@@ -57,6 +58,7 @@ pub fn purpose(&self) -> String {
             Codegen => "Contribute to the compiler, and also modify LLVM or codegen",
             Tools => "Contribute to tools which depend on the compiler, but do not modify it directly (e.g. rustdoc, clippy, miri)",
             Dist => "Install Rust from source",
+            Zkvm => "Build an installable toolchain for zkVM guests from source",
             None => "Do not modify `config.toml`"
         }
         .to_string()
@@ -77,6 +79,7 @@ pub fn as_str(&self) -> &'static str {
             Profile::Library => "library",
             Profile::Tools => "tools",
             Profile::Dist => "dist",
+            Profile::Zkvm => "zkvm",
             Profile::None => "none",
         }
     }
@@ -91,6 +94,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "compiler" => Ok(Profile::Compiler),
             "llvm" | "codegen" => Ok(Profile::Codegen),
             "maintainer" | "dist" | "user" => Ok(Profile::Dist),
+            "zkvm" => Ok(Profile::Zkvm),
             "tools" | "tool" | "rustdoc" | "clippy" | "miri" | "rustfmt" | "rls" => {
                 Ok(Profile::Tools)
             }
@@ -194,6 +198,7 @@ pub fn setup(config: &Config, profile: Profile) {
         ],
         Profile::Library => &["check", "build", "test library/std", "doc"],
         Profile::Dist => &["dist", "build"],
+        Profile::Zkvm => &["dist"],
     };
 
     println!();
@@ -203,7 +208,7 @@ pub fn setup(config: &Config, profile: Profile) {
         println!("- `x.py {cmd}`");
     }
 
-    if profile != Profile::Dist {
+    if !matches!(profile, Profile::Dist | Profile::Zkvm) {
         println!(
             "For more suggestions, see https://rustc-dev-guide.rust-lang.org/building/suggested.html"
         );
@@ -1724,6 +1724,11 @@ fn run(self, builder: &Builder<'_>) {
         if let Some(ref npm) = builder.config.npm {
             cmd.arg("--npm").arg(npm);
         }
+        if target.contains("zkvm") {
+            if let Some(runner) = builder.runner(target) {
+                cmd.arg("--zkvm-runner").arg(runner);
+            }
+        }
         if builder.config.rust_optimize_tests {
             cmd.arg("--optimize-tests");
         }
@@ -2433,6 +2438,16 @@ fn prepare_cargo_test(
         let node = builder.config.nodejs.as_ref().expect("nodejs not configured");
         let runner = format!("{} {}/src/etc/wasm32-shim.js", node.display(), builder.src.display());
         cargo.env(format!("CARGO_TARGET_{}_RUNNER", envify(&target.triple)), &runner);
+    } else if target.contains("zkvm") {
+        let Some(runner) = builder.runner(target) else {
+            eprintln!(
+                "\
+ERROR: no runner configured for {target}
+HELP: set `runner` in the `[target.{target}]` section of `config.toml` to a zkVM executor"
+            );
+            crate::exit!(1);
+        };
+        cargo.env(format!("CARGO_TARGET_{}_RUNNER", envify(&target.triple)), runner);
     } else if builder.remote_tested(target) {
         cargo.env(
             format!("CARGO_TARGET_{}_RUNNER", envify(&target.triple)),
@@ -255,6 +255,7 @@ pub struct Config {
     pub dist_compression_formats: Option<Vec<String>>,
     pub dist_compression_profile: String,
     pub dist_include_mingw_linker: bool,
+    pub dist_include_std_targets: Vec<TargetSelection>,
 
     // libstd features
     pub backtrace: bool, // support for RUST_BACKTRACE
@@ -532,6 +533,7 @@ pub struct Target {
     pub musl_libdir: Option<PathBuf>,
     pub wasi_root: Option<PathBuf>,
     pub qemu_rootfs: Option<PathBuf>,
+    pub runner: Option<String>,
     pub no_std: bool,
 }
 
@@ -860,6 +862,7 @@ struct Dist {
         compression_formats: Option<Vec<String>> = "compression-formats",
         compression_profile: Option<String> = "compression-profile",
         include_mingw_linker: Option<bool> = "include-mingw-linker",
+        include_std_targets: Option<Vec<String>> = "include-std-targets",
     }
 }
 
@@ -1050,6 +1053,7 @@ struct TomlTarget {
         musl_libdir: Option<String> = "musl-libdir",
         wasi_root: Option<String> = "wasi-root",
         qemu_rootfs: Option<String> = "qemu-rootfs",
+        runner: Option<String> = "runner",
         no_std: Option<bool> = "no-std",
     }
 }
@@ -1610,6 +1614,7 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
                 target.musl_libdir = cfg.musl_libdir.map(PathBuf::from);
                 target.wasi_root = cfg.wasi_root.map(PathBuf::from);
                 target.qemu_rootfs = cfg.qemu_rootfs.map(PathBuf::from);
+                target.runner = cfg.runner;
                 target.sanitizers = cfg.sanitizers;
                 target.profiler = cfg.profiler;
                 target.rpath = cfg.rpath;
@@ -1639,7 +1644,18 @@ fn get_table(option: &str) -> Result<TomlConfig, toml::de::Error> {
             set(&mut config.dist_compression_profile, t.compression_profile);
             set(&mut config.rust_dist_src, t.src_tarball);
             set(&mut config.missing_tools, t.missing_tools);
-            set(&mut config.dist_include_mingw_linker, t.include_mingw_linker)
+            set(&mut config.dist_include_mingw_linker, t.include_mingw_linker);
+            if let Some(std_targets) = t.include_std_targets {
+                config.dist_include_std_targets =
+                    std_targets.iter().map(|t| TargetSelection::from_user(t)).collect();
+            }
+        }
+
+        // The standard library can only be shipped for targets it is built for.
+        for &target in &config.dist_include_std_targets {
+            if !config.targets.contains(&target) {
+                config.targets.push(target);
+            }
         }
 
         if let Some(r) = build.rustfmt {
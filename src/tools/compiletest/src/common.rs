@@ -370,6 +370,8 @@ pub struct Config {
     pub nodejs: Option<String>,
     /// Path to a npm executable. Used for rustdoc GUI tests
     pub npm: Option<String>,
+    /// Path to an emulator that run-pass tests for zkVM targets are run under
+    pub zkvm_runner: Option<String>,
 
     /// Whether to rerun tests even if the inputs are unchanged.
     pub force_rerun: bool,
@@ -391,6 +393,8 @@ pub fn run_enabled(&self) -> bool {
         self.run.unwrap_or_else(|| {
             // Auto-detect whether to run based on the platform.
             !self.target.ends_with("-fuchsia")
+                // zkVM programs can only be run under an emulator.
+                && !(self.target.contains("zkvm") && self.zkvm_runner.is_none())
         })
     }
 
@@ -683,6 +687,7 @@ pub fn expected_output_path(
     UI_FIXED,
     UI_RUN_STDERR,
     UI_RUN_STDOUT,
+    UI_RUN_JOURNAL,
     UI_STDERR_64,
     UI_STDERR_32,
     UI_STDERR_16,
@@ -694,6 +699,7 @@ pub fn expected_output_path(
 pub const UI_FIXED: &str = "fixed";
 pub const UI_RUN_STDERR: &str = "run.stderr";
 pub const UI_RUN_STDOUT: &str = "run.stdout";
+pub const UI_RUN_JOURNAL: &str = "journal";
 pub const UI_STDERR_64: &str = "64bit.stderr";
 pub const UI_STDERR_32: &str = "32bit.stderr";
 pub const UI_STDERR_16: &str = "16bit.stderr";
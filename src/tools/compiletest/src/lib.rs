@@ -125,6 +125,7 @@ pub fn parse_config(args: Vec<String>) -> Config {
         .optopt("", "llvm-bin-dir", "Path to LLVM's `bin` directory", "PATH")
         .optopt("", "nodejs", "the name of nodejs", "PATH")
         .optopt("", "npm", "the name of npm", "PATH")
+        .optopt("", "zkvm-runner", "emulator to run zkVM tests under", "PATH")
         .optopt("", "remote-test-client", "path to the remote test client", "PATH")
         .optopt(
             "",
@@ -303,6 +304,7 @@ fn make_absolute(path: PathBuf) -> PathBuf {
         llvm_components: matches.opt_str("llvm-components").unwrap(),
         nodejs: matches.opt_str("nodejs"),
         npm: matches.opt_str("npm"),
+        zkvm_runner: matches.opt_str("zkvm-runner"),
 
         force_rerun: matches.opt_present("force-rerun"),
 
@@ -7,7 +7,7 @@
 use crate::common::{CompareMode, FailMode, PassMode};
 use crate::common::{Config, TestPaths};
 use crate::common::{CoverageMap, CoverageRun, Pretty, RunPassValgrind};
-use crate::common::{UI_COVERAGE, UI_COVERAGE_MAP, UI_RUN_STDERR, UI_RUN_STDOUT};
+use crate::common::{UI_COVERAGE, UI_COVERAGE_MAP, UI_RUN_JOURNAL, UI_RUN_STDERR, UI_RUN_STDOUT};
 use crate::compute_diff::{write_diff, write_filtered_diff};
 use crate::errors::{self, Error, ErrorKind};
 use crate::header::TestProps;
@@ -1976,10 +1976,20 @@ fn exec_compiled_test_general(
         env_extra: &[(&str, &str)],
         delete_after_success: bool,
     ) -> ProcRes {
+        // The zkVM runner writes what the test commits to the file named by `ZKVM_JOURNAL`. Don't
+        // let the journal of an earlier run pass for this one's.
+        let journal = self.config.target.contains("zkvm").then(|| self.make_journal_name());
+        if let Some(journal) = &journal {
+            let _ = fs::remove_file(journal);
+        }
+
         let prepare_env = |cmd: &mut Command| {
             for key in &self.props.unset_exec_env {
                 cmd.env_remove(key);
             }
+            if let Some(journal) = &journal {
+                cmd.env("ZKVM_JOURNAL", journal);
+            }
 
             for (key, val) in &self.props.exec_env {
                 cmd.env(key, val);
@@ -2621,6 +2631,11 @@ fn make_exe_name(&self) -> PathBuf {
         f
     }
 
+    /// The file the zkVM runner writes the journal of the test to.
+    fn make_journal_name(&self) -> PathBuf {
+        self.output_base_dir().join("a.journal")
+    }
+
     fn make_run_args(&self) -> ProcArgs {
         // If we've got another tool to run under (valgrind),
         // then split apart its command
@@ -2650,6 +2665,13 @@ fn make_run_args(&self) -> ProcArgs {
                 .parent()
                 .unwrap(); // chop off `tests`
             args.push(src.join("src/etc/wasm32-shim.js").into_os_string());
+        // If this is a zkVM, then run tests under the configured emulator
+        } else if self.config.target.contains("zkvm") {
+            if let Some(ref p) = self.config.zkvm_runner {
+                args.push(p.into());
+            } else {
+                self.fatal("zkvm target requested and no runner found (--zkvm-runner)");
+            }
         }
 
         let exe_file = self.make_exe_name();
@@ -3779,6 +3801,18 @@ fn load_compare_outputs(
                     &expected_stderr,
                     self.props.compare_output_lines_by_subset,
                 );
+                if self.config.target.contains("zkvm") {
+                    // A test that commits nothing may not leave a journal behind.
+                    let journal = fs::read(self.make_journal_name()).unwrap_or_default();
+                    let journal = String::from_utf8_lossy(&journal);
+                    let expected_journal = self.load_expected_output(UI_RUN_JOURNAL);
+                    errors += self.compare_output(
+                        UI_RUN_JOURNAL,
+                        &journal,
+                        &expected_journal,
+                        self.props.compare_output_lines_by_subset,
+                    );
+                }
             }
         }
         errors
@@ -36,7 +36,7 @@
 use rustc_session::search_paths::PathKind;
 use rustc_session::{CtfeBacktrace, EarlyErrorHandler};
 
-use miri::{BacktraceStyle, BorrowTrackerMethod, ProvenanceMode, RetagFields};
+use miri::{BacktraceStyle, BorrowTrackerMethod, CostTable, ProvenanceMode, RetagFields};
 
 struct MiriCompilerCalls {
     miri_config: miri::MiriConfig,
@@ -568,11 +568,30 @@ fn main() {
             };
 
             miri_config.page_size = Some(page_size);
+        } else if let Some(param) = arg.strip_prefix("-Zmiri-zkvm-stdin=") {
+            let filename = param.to_string();
+            if !std::path::Path::new(&filename).exists() {
+                show_error!("-Zmiri-zkvm-stdin `{}` does not exist", filename);
+            }
+            miri_config.zkvm_stdin = Some(filename.into());
+        } else if let Some(param) = arg.strip_prefix("-Zmiri-zkvm-journal=") {
+            miri_config.zkvm_journal = Some(param.into());
+        } else if let Some(param) = arg.strip_prefix("-Zmiri-cost-model=") {
+            let table = match CostTable::from_file(std::path::Path::new(param)) {
+                Ok(table) => table,
+                Err(err) => show_error!("-Zmiri-cost-model: {err}"),
+            };
+            miri_config.cost_model = Some(table);
+        } else if let Some(param) = arg.strip_prefix("-Zmiri-cost-profile=") {
+            miri_config.cost_profile = Some(param.into());
         } else {
             // Forward to rustc.
             rustc_args.push(arg);
         }
     }
+    if miri_config.cost_profile.is_some() && miri_config.cost_model.is_none() {
+        show_error!("-Zmiri-cost-profile only has an effect when -Zmiri-cost-model is also used");
+    }
     // `-Zmiri-unique-is-unique` should only be used with `-Zmiri-tree-borrows`
     if miri_config.unique_is_unique
         && !matches!(miri_config.borrow_tracker, Some(BorrowTrackerMethod::TreeBorrows))
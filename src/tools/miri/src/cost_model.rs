@@ -0,0 +1,288 @@
+//! Estimation of what executing the program would cost on its target, for `-Zmiri-cost-model`.
+//!
+//! A [`CostTable`] assigns costs to MIR statements, terminators, and calls to shimmed foreign
+//! functions. As the program runs, Miri charges these costs to the call stack they are incurred
+//! in. At the end, the costs are written out as a folded-stack profile, which tools like
+//! `inferno-flamegraph` or `flamegraph.pl` turn into flame graphs.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir;
+use rustc_middle::ty::{self, Instance, TyCtxt};
+
+use crate::*;
+
+/// The costs of the operations Miri executes, read from the file passed to `-Zmiri-cost-model`.
+///
+/// Each line of the file has the form `<key> = <cost>`, and `#` starts a comment. The cost of an
+/// operation is the cost of the most specific key present in the table, or 0 if there is none:
+///
+/// - statements: `statement.<kind>`, then `statement`. Assignments first try `float.<op>` (for
+///   arithmetic on floats) or `binop.<op>` (for other binary operations), then `rvalue.<kind>`.
+/// - terminators: `terminator.<kind>`, then `terminator`.
+/// - shimmed foreign functions: `call.<link name>`, on top of the cost of the `Call` terminator.
+///   Some shims additionally charge for the amount of work they do, e.g. `sys_sha_buffer` charges
+///   `call.sys_sha_buffer.per-block` for each block.
+///
+/// Kinds and operators are spelled like their MIR variants, e.g. `statement.StorageLive`,
+/// `rvalue.Aggregate`, `binop.Mul`, or `terminator.SwitchInt`.
+#[derive(Clone, Debug, Default)]
+pub struct CostTable {
+    costs: FxHashMap<String, u64>,
+}
+
+impl CostTable {
+    pub fn from_file(path: &Path) -> Result<CostTable, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read `{}`: {err}", path.display()))?;
+        CostTable::parse(&contents)
+    }
+
+    fn parse(contents: &str) -> Result<CostTable, String> {
+        let mut costs = FxHashMap::default();
+        for (line_idx, line) in contents.lines().enumerate() {
+            let line_no = line_idx + 1;
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((key, cost)) = line.split_once('=') else {
+                return Err(format!("line {line_no}: expected `<key> = <cost>`"));
+            };
+            let cost = cost
+                .trim()
+                .parse::<u64>()
+                .map_err(|err| format!("line {line_no}: invalid cost: {err}"))?;
+            costs.insert(key.trim().to_owned(), cost);
+        }
+        Ok(CostTable { costs })
+    }
+
+    /// Returns the cost of the first of `keys` that is present in the table, or 0.
+    fn lookup(&self, keys: &[&str]) -> u64 {
+        keys.iter().find_map(|key| self.costs.get(*key)).copied().unwrap_or(0)
+    }
+
+    fn statement_cost<'tcx>(
+        &self,
+        tcx: TyCtxt<'tcx>,
+        instance: Instance<'tcx>,
+        body: &mir::Body<'tcx>,
+        statement: &mir::Statement<'tcx>,
+    ) -> u64 {
+        let kind = format!("statement.{}", statement.kind.name());
+        let mir::StatementKind::Assign(assign) = &statement.kind else {
+            return self.lookup(&[&kind, "statement"]);
+        };
+        let (_, rvalue) = &**assign;
+
+        let rvalue_kind = format!("rvalue.{}", rvalue_name(rvalue));
+        let op = match rvalue {
+            mir::Rvalue::BinaryOp(op, operands) | mir::Rvalue::CheckedBinaryOp(op, operands) => {
+                let (lhs, _) = &**operands;
+                let ty = instance.instantiate_mir_and_normalize_erasing_regions(
+                    tcx,
+                    ty::ParamEnv::reveal_all(),
+                    ty::EarlyBinder::bind(lhs.ty(body, tcx)),
+                );
+                let category = if ty.is_floating_point() { "float" } else { "binop" };
+                Some(format!("{category}.{op:?}"))
+            }
+            _ => None,
+        };
+        match op {
+            Some(op) => self.lookup(&[&op, &rvalue_kind, &kind, "statement"]),
+            None => self.lookup(&[&rvalue_kind, &kind, "statement"]),
+        }
+    }
+
+    fn terminator_cost(&self, terminator: &mir::Terminator<'_>) -> u64 {
+        self.lookup(&[&format!("terminator.{}", terminator.kind.name()), "terminator"])
+    }
+}
+
+fn rvalue_name(rvalue: &mir::Rvalue<'_>) -> &'static str {
+    use mir::Rvalue::*;
+    match rvalue {
+        Use(..) => "Use",
+        Repeat(..) => "Repeat",
+        Ref(..) => "Ref",
+        ThreadLocalRef(..) => "ThreadLocalRef",
+        AddressOf(..) => "AddressOf",
+        Len(..) => "Len",
+        Cast(..) => "Cast",
+        BinaryOp(..) => "BinaryOp",
+        CheckedBinaryOp(..) => "CheckedBinaryOp",
+        NullaryOp(..) => "NullaryOp",
+        UnaryOp(..) => "UnaryOp",
+        Discriminant(..) => "Discriminant",
+        Aggregate(..) => "Aggregate",
+        ShallowInitBox(..) => "ShallowInitBox",
+        CopyForDeref(..) => "CopyForDeref",
+    }
+}
+
+/// Identifies a call stack that costs are charged to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CostStackId(usize);
+
+/// The state of `-Zmiri-cost-model`.
+pub struct CostModel<'tcx> {
+    table: CostTable,
+    /// Where the profile is written to.
+    profile_path: PathBuf,
+    /// The call stacks seen so far, as the stack of the caller and the function on top.
+    stacks: Vec<(Option<CostStackId>, Instance<'tcx>)>,
+    stack_ids: FxHashMap<(Option<CostStackId>, Instance<'tcx>), CostStackId>,
+    /// The cost charged to each stack, not including the cost of its callees.
+    costs: Vec<u64>,
+    /// The cost of the statements and the terminator of each basic block executed so far.
+    block_costs: FxHashMap<(Instance<'tcx>, mir::BasicBlock), u64>,
+    /// The sum of all costs charged so far.
+    total: u64,
+}
+
+impl<'tcx> CostModel<'tcx> {
+    pub fn new(table: CostTable, profile_path: PathBuf) -> Self {
+        CostModel {
+            table,
+            profile_path,
+            stacks: Vec::new(),
+            stack_ids: FxHashMap::default(),
+            costs: Vec::new(),
+            block_costs: FxHashMap::default(),
+            total: 0,
+        }
+    }
+
+    /// Returns the stack that results from `caller` calling `instance`.
+    pub fn push_frame(
+        &mut self,
+        caller: Option<CostStackId>,
+        instance: Instance<'tcx>,
+    ) -> CostStackId {
+        *self.stack_ids.entry((caller, instance)).or_insert_with(|| {
+            self.stacks.push((caller, instance));
+            self.costs.push(0);
+            CostStackId(self.stacks.len() - 1)
+        })
+    }
+
+    pub fn charge(&mut self, stack: CostStackId, cost: u64) {
+        self.costs[stack.0] = self.costs[stack.0].saturating_add(cost);
+        self.total = self.total.saturating_add(cost);
+    }
+
+    /// The cost of executing all of `block`, which is computed once and then cached.
+    pub fn block_cost(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        instance: Instance<'tcx>,
+        body: &mir::Body<'tcx>,
+        block: mir::BasicBlock,
+    ) -> u64 {
+        let table = &self.table;
+        *self.block_costs.entry((instance, block)).or_insert_with(|| {
+            let data = &body.basic_blocks[block];
+            let statements = data
+                .statements
+                .iter()
+                .map(|statement| table.statement_cost(tcx, instance, body, statement));
+            statements
+                .chain(data.terminator.iter().map(|terminator| table.terminator_cost(terminator)))
+                .fold(0, u64::saturating_add)
+        })
+    }
+
+    /// The sum of all costs charged so far.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Writes the costs in the folded-stack format: one line per call stack, listing the
+    /// functions from the outermost to the innermost, separated by `;`, followed by the cost.
+    pub fn write_profile(&self) -> io::Result<()> {
+        let mut profile = String::new();
+        for (idx, &cost) in self.costs.iter().enumerate() {
+            if cost == 0 {
+                continue;
+            }
+            let mut frames = Vec::new();
+            let mut stack = Some(CostStackId(idx));
+            while let Some(CostStackId(idx)) = stack {
+                let (caller, instance) = self.stacks[idx];
+                // `;` separates the frames, so it must not appear in their names, but it does
+                // appear in array types.
+                frames.push(instance.to_string().replace(';', ","));
+                stack = caller;
+            }
+            frames.reverse();
+            writeln!(profile, "{} {cost}", frames.join(";")).unwrap();
+        }
+        fs::write(&self.profile_path, profile)
+    }
+}
+
+impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+pub trait EvalContextExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    /// Charges the cost of the basic block that is about to finish to the current stack.
+    fn charge_block_cost(&mut self) {
+        let this = self.eval_context_mut();
+        if this.machine.cost_model.is_none() {
+            return;
+        }
+        let tcx = this.tcx.tcx;
+        let frame = this.frame();
+        let (instance, body, stack) = (frame.instance, frame.body, frame.extra.cost_stack);
+        let block = frame.loc.left().expect("a terminator is about to be executed").block;
+        let cost_model = this.machine.cost_model.as_mut().unwrap();
+        let cost = cost_model.block_cost(tcx, instance, body, block);
+        cost_model.charge(stack.unwrap(), cost);
+    }
+
+    /// Charges `units` times the cost of `key` to the current stack. Shims use this, as they do
+    /// not execute any MIR.
+    fn charge_cost(&mut self, key: &str, units: u64) {
+        let this = self.eval_context_mut();
+        let Some(cost_model) = &this.machine.cost_model else { return };
+        let cost = cost_model.table.lookup(&[key]).saturating_mul(units);
+        let stack = this.frame().extra.cost_stack.unwrap();
+        this.machine.cost_model.as_mut().unwrap().charge(stack, cost);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cost_table() {
+        let table = CostTable::parse(
+            "# Costs are in cycles.\n\
+             statement = 1\n\
+             \n\
+             statement.StorageLive = 0 # free\n\
+             binop.Div=2\n",
+        )
+        .unwrap();
+        assert_eq!(table.lookup(&["statement.StorageLive", "statement"]), 0);
+        assert_eq!(table.lookup(&["statement.Assign", "statement"]), 1);
+        assert_eq!(table.lookup(&["binop.Div", "statement"]), 2);
+        assert_eq!(table.lookup(&["terminator"]), 0);
+    }
+
+    #[test]
+    fn parse_cost_table_errors() {
+        assert_eq!(
+            CostTable::parse("statement = 1\nterminator\n").unwrap_err(),
+            "line 2: expected `<key> = <cost>`"
+        );
+        assert!(
+            CostTable::parse("statement = -1").unwrap_err().starts_with("line 1: invalid cost")
+        );
+    }
+}
@@ -153,6 +153,14 @@ pub struct MiriConfig {
     pub page_size: Option<u64>,
     /// Whether to collect a backtrace when each allocation is created, just in case it leaks.
     pub collect_leak_backtraces: bool,
+    /// The file whose contents a zkVM guest reads from stdin.
+    pub zkvm_stdin: Option<PathBuf>,
+    /// The file a zkVM guest's journal is written to.
+    pub zkvm_journal: Option<PathBuf>,
+    /// If `Some`, estimate the cost of executing the program using this table.
+    pub cost_model: Option<CostTable>,
+    /// The file the cost profile is written to. Defaults to one named after the crate.
+    pub cost_profile: Option<PathBuf>,
 }
 
 impl Default for MiriConfig {
@@ -189,6 +197,10 @@ fn default() -> MiriConfig {
             num_cpus: 1,
             page_size: None,
             collect_leak_backtraces: true,
+            zkvm_stdin: None,
+            zkvm_journal: None,
+            cost_model: None,
+            cost_profile: None,
         }
     }
 }
@@ -457,6 +469,13 @@ pub fn eval_entry<'tcx>(
         EnvVars::cleanup(&mut ecx).expect("error during env var cleanup");
     }
 
+    // Write the cost profile, also if the program did not terminate normally.
+    if let Some(cost_model) = &ecx.machine.cost_model {
+        if let Err(err) = cost_model.write_profile() {
+            tcx.sess.warn(format!("failed to write the cost profile: {err}"));
+        }
+    }
+
     // Process the result.
     let (return_code, leak_check) = report_error(&ecx, res)?;
     if leak_check && !ignore_leaks {
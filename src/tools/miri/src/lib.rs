@@ -70,6 +70,7 @@
 mod borrow_tracker;
 mod clock;
 mod concurrency;
+mod cost_model;
 mod diagnostics;
 mod eval;
 mod helpers;
@@ -111,6 +112,7 @@
     sync::{CondvarId, EvalContextExt as _, MutexId, RwLockId, SyncId},
     thread::{EvalContextExt as _, StackEmptyCallback, ThreadId, ThreadManager, Time},
 };
+pub use crate::cost_model::{CostModel, CostStackId, CostTable, EvalContextExt as _};
 pub use crate::diagnostics::{
     report_error, EvalContextExt as _, NonHaltingDiagnostic, TerminationInfo,
 };
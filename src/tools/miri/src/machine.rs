@@ -23,7 +23,7 @@
         Instance, Ty, TyCtxt, TypeAndMut,
     },
 };
-use rustc_span::def_id::{CrateNum, DefId};
+use rustc_span::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use rustc_span::{Span, SpanData, Symbol};
 use rustc_target::abi::{Align, Size};
 use rustc_target::spec::abi::Abi;
@@ -31,6 +31,7 @@
 use crate::{
     concurrency::{data_race, weak_memory},
     shims::unix::FileHandler,
+    shims::zkvm::ZkvmIo,
     *,
 };
 
@@ -64,12 +65,22 @@ pub struct FrameExtra<'tcx> {
     /// optimization.
     /// This is used by `MiriMachine::current_span` and `MiriMachine::caller_span`
     pub is_user_relevant: bool,
+
+    /// If `-Zmiri-cost-model` is enabled, the call stack that the costs incurred in this
+    /// frame are charged to.
+    pub cost_stack: Option<CostStackId>,
 }
 
 impl<'tcx> std::fmt::Debug for FrameExtra<'tcx> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Omitting `timing`, it does not support `Debug`.
-        let FrameExtra { borrow_tracker, catch_unwind, timing: _, is_user_relevant: _ } = self;
+        let FrameExtra {
+            borrow_tracker,
+            catch_unwind,
+            timing: _,
+            is_user_relevant: _,
+            cost_stack: _,
+        } = self;
         f.debug_struct("FrameData")
             .field("borrow_tracker", borrow_tracker)
             .field("catch_unwind", catch_unwind)
@@ -79,7 +90,13 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 
 impl VisitTags for FrameExtra<'_> {
     fn visit_tags(&self, visit: &mut dyn FnMut(BorTag)) {
-        let FrameExtra { catch_unwind, borrow_tracker, timing: _, is_user_relevant: _ } = self;
+        let FrameExtra {
+            catch_unwind,
+            borrow_tracker,
+            timing: _,
+            is_user_relevant: _,
+            cost_stack: _,
+        } = self;
 
         catch_unwind.visit_tags(visit);
         borrow_tracker.visit_tags(visit);
@@ -114,6 +131,9 @@ pub enum MiriMemoryKind {
     Tls,
     /// Memory mapped directly by the program
     Mmap,
+    /// zkVM `sys_alloc_*` memory.
+    /// The zkVM never deallocates, so this memory may leak.
+    ZkvmHeap,
 }
 
 impl From<MiriMemoryKind> for MemoryKind<MiriMemoryKind> {
@@ -129,7 +149,7 @@ fn may_leak(self) -> bool {
         use self::MiriMemoryKind::*;
         match self {
             Rust | Miri | C | WinHeap | Runtime => false,
-            Machine | Global | ExternStatic | Tls | Mmap => true,
+            Machine | Global | ExternStatic | Tls | Mmap | ZkvmHeap => true,
         }
     }
 }
@@ -140,7 +160,7 @@ fn should_save_allocation_span(self) -> bool {
         use self::MiriMemoryKind::*;
         match self {
             // Heap allocations are fine since the `Allocation` is created immediately.
-            Rust | Miri | C | WinHeap | Mmap => true,
+            Rust | Miri | C | WinHeap | Mmap | ZkvmHeap => true,
             // Everything else is unclear, let's not show potentially confusing spans.
             Machine | Global | ExternStatic | Tls | Runtime => false,
         }
@@ -161,6 +181,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             ExternStatic => write!(f, "extern static"),
             Tls => write!(f, "thread-local static"),
             Mmap => write!(f, "mmap"),
+            ZkvmHeap => write!(f, "zkVM heap"),
         }
     }
 }
@@ -440,6 +461,8 @@ pub struct MiriMachine<'mir, 'tcx> {
     pub(crate) file_handler: shims::unix::FileHandler,
     /// The table of directory descriptors.
     pub(crate) dir_handler: shims::unix::DirHandler,
+    /// The zkVM's stdin and journal.
+    pub(crate) zkvm_io: shims::zkvm::ZkvmIo,
 
     /// This machine's monotone clock.
     pub(crate) clock: Clock,
@@ -505,6 +528,8 @@ pub struct MiriMachine<'mir, 'tcx> {
     pub(crate) report_progress: Option<u32>,
     // The total number of blocks that have been executed.
     pub(crate) basic_block_count: u64,
+    /// If `Some`, the costs of the executed operations are tracked.
+    pub(crate) cost_model: Option<CostModel<'tcx>>,
 
     /// Handle of the optional shared object file for external functions.
     #[cfg(target_os = "linux")]
@@ -602,6 +627,7 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             enforce_abi: config.check_abi,
             file_handler: FileHandler::new(config.mute_stdout_stderr),
             dir_handler: Default::default(),
+            zkvm_io: ZkvmIo::new(tcx, config),
             layouts,
             threads: ThreadManager::default(),
             static_roots: Vec::new(),
@@ -621,6 +647,14 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             preemption_rate: config.preemption_rate,
             report_progress: config.report_progress,
             basic_block_count: 0,
+            cost_model: config.cost_model.clone().map(|table| {
+                let profile_path = config.cost_profile.clone().unwrap_or_else(|| {
+                    // Like the `measureme` profiles, name it after the crate and the process.
+                    let crate_name = tcx.crate_name(LOCAL_CRATE);
+                    format!("{crate_name}-{:07}.folded", process::id()).into()
+                });
+                CostModel::new(table, profile_path)
+            }),
             clock: Clock::new(config.isolated_op == IsolatedOp::Allow),
             #[cfg(target_os = "linux")]
             external_so_lib: config.external_so_file.as_ref().map(|lib_file_path| {
@@ -832,6 +866,7 @@ fn visit_tags(&self, visit: &mut dyn FnMut(BorTag)) {
             preemption_rate: _,
             report_progress: _,
             basic_block_count: _,
+            cost_model: _,
             external_so_lib: _,
             gc_interval: _,
             since_gc: _,
@@ -841,6 +876,7 @@ fn visit_tags(&self, visit: &mut dyn FnMut(BorTag)) {
             stack_size: _,
             collect_leak_backtraces: _,
             allocation_spans: _,
+            zkvm_io: _,
         } = self;
 
         threads.visit_tags(visit);
@@ -1338,6 +1374,14 @@ fn init_frame_extra(
             None
         };
 
+        let caller_cost_stack =
+            ecx.active_thread_stack().last().and_then(|caller| caller.extra.cost_stack);
+        let cost_stack = ecx
+            .machine
+            .cost_model
+            .as_mut()
+            .map(|cost_model| cost_model.push_frame(caller_cost_stack, frame.instance));
+
         let borrow_tracker = ecx.machine.borrow_tracker.as_ref();
 
         let extra = FrameExtra {
@@ -1345,6 +1389,7 @@ fn init_frame_extra(
             catch_unwind: None,
             timing,
             is_user_relevant: ecx.machine.is_user_relevant(&frame),
+            cost_stack,
         };
 
         Ok(frame.with_extra(extra))
@@ -1365,6 +1410,7 @@ fn stack_mut<'a>(
     fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
         ecx.machine.basic_block_count += 1u64; // a u64 that is only incremented by 1 will "never" overflow
         ecx.machine.since_gc += 1;
+        ecx.charge_block_cost();
         // Possibly report our progress.
         if let Some(report_progress) = ecx.machine.report_progress {
             if ecx.machine.basic_block_count % u64::from(report_progress) == 0 {
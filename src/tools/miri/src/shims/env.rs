@@ -10,6 +10,8 @@
 use rustc_target::abi::Size;
 
 use crate::helpers::target_os_is_unix;
+use crate::shims::os_str::bytes_to_os_str;
+use crate::shims::zkvm::WORD_SIZE;
 use crate::*;
 
 /// Check whether an operation that writes to a target buffer was successful.
@@ -62,7 +64,7 @@ pub(crate) fn init<'mir>(
                     || config.forwarded_env_vars.iter().any(|v| **v == *name);
                 if forward {
                     let var_ptr = match target_os {
-                        target if target_os_is_unix(target) =>
+                        target if target_os_is_unix(target) || target == "zkvm" =>
                             alloc_env_var_as_c_str(name.as_ref(), value.as_ref(), ecx)?,
                         "windows" => alloc_env_var_as_wide_str(name.as_ref(), value.as_ref(), ecx)?,
                         unsupported =>
@@ -139,6 +141,44 @@ fn getenv(
         })
     }
 
+    fn sys_getenv(
+        &mut self,
+        recv_buf_op: &OpTy<'tcx, Provenance>,    // *mut u32
+        words_op: &OpTy<'tcx, Provenance>,       // usize
+        varname_op: &OpTy<'tcx, Provenance>,     // *const u8
+        varname_len_op: &OpTy<'tcx, Provenance>, // usize
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+        this.assert_target_os("zkvm", "sys_getenv");
+
+        let recv_buf = this.read_pointer(recv_buf_op)?;
+        let words = this.read_target_usize(words_op)?;
+        let varname = this.read_pointer(varname_op)?;
+        let varname_len = this.read_target_usize(varname_len_op)?;
+        let name = this.read_bytes_ptr_strip_provenance(varname, Size::from_bytes(varname_len))?;
+        let name = bytes_to_os_str(name)?.to_owned();
+
+        // The guest first asks for the length with an empty buffer, then for the value. If the
+        // variable is not set, the result is `usize::MAX`.
+        let Some(&var_ptr) = this.machine.env_vars.map.get(&name) else {
+            return Ok(Scalar::from_target_usize(this.target_usize_max(), this));
+        };
+        // The offset is used to strip the "{name}=" part of the string.
+        let value_ptr = var_ptr.offset(
+            Size::from_bytes(u64::try_from(name.len()).unwrap().checked_add(1).unwrap()),
+            this,
+        )?;
+        let value = this.read_c_str(value_ptr)?.to_owned();
+
+        // Copy as much of the value as fits into the buffer.
+        let capacity = words.checked_mul(WORD_SIZE).unwrap();
+        let copied = value.len().min(usize::try_from(capacity).unwrap_or(usize::MAX));
+        if copied > 0 {
+            this.write_bytes_ptr(recv_buf, value[..copied].iter().copied())?;
+        }
+        Ok(Scalar::from_target_usize(u64::try_from(value.len()).unwrap(), this))
+    }
+
     #[allow(non_snake_case)]
     fn GetEnvironmentVariableW(
         &mut self,
@@ -66,6 +66,11 @@ fn emulate_foreign_item(
         let this = self.eval_context_mut();
         let tcx = this.tcx.tcx;
 
+        // Shims do not execute any MIR, so they are charged for as a whole.
+        if this.machine.cost_model.is_some() {
+            this.charge_cost(&format!("call.{link_name}"), 1);
+        }
+
         // First: functions that diverge.
         let ret = match ret {
             None =>
@@ -1062,6 +1067,10 @@ fn emulate_foreign_item_inner(
                         shims::windows::foreign_items::EvalContextExt::emulate_foreign_item_inner(
                             this, link_name, abi, args, dest,
                         ),
+                    "zkvm" =>
+                        shims::zkvm::foreign_items::EvalContextExt::emulate_foreign_item_inner(
+                            this, link_name, abi, args, dest,
+                        ),
                     _ => Ok(EmulateForeignItemResult::NotSupported),
                 },
         };
@@ -8,6 +8,7 @@
 pub mod unix;
 pub mod windows;
 mod x86;
+pub mod zkvm;
 
 pub mod env;
 pub mod os_str;
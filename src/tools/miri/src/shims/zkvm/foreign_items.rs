@@ -0,0 +1,241 @@
+use std::io::{self, Write};
+
+use rustc_span::Symbol;
+use rustc_target::abi::{Align, Size};
+use rustc_target::spec::abi::Abi;
+
+use crate::*;
+use shims::foreign_items::EmulateForeignItemResult;
+use shims::zkvm::sha256::EvalContextExt as _;
+use shims::zkvm::WORD_SIZE;
+
+/// File descriptors understood by `sys_read` and `sys_write`.
+const STDIN: u32 = 0;
+const STDOUT: u32 = 1;
+const STDERR: u32 = 2;
+const JOURNAL: u32 = 3;
+
+impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+pub trait EvalContextExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    fn emulate_foreign_item_inner(
+        &mut self,
+        link_name: Symbol,
+        abi: Abi,
+        args: &[OpTy<'tcx, Provenance>],
+        dest: &PlaceTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, EmulateForeignItemResult> {
+        let this = self.eval_context_mut();
+
+        // See `fn emulate_foreign_item_inner` in `shims/foreign_items.rs` for the general pattern.
+
+        // zkVM syscalls, as declared in `library/std/src/sys/zkvm/abi/succinct.rs`.
+        match link_name.as_str() {
+            // Program termination
+            "sys_halt" => {
+                let [] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                throw_machine_stop!(TerminationInfo::Exit { code: 0, leak_check: false });
+            }
+            "sys_panic" => {
+                let [msg, len] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let msg = this.read_pointer(msg)?;
+                let len = this.read_target_usize(len)?;
+                let msg = this.read_bytes_ptr_strip_provenance(msg, Size::from_bytes(len))?;
+                throw_machine_stop!(TerminationInfo::Abort(format!(
+                    "the guest panicked: {}",
+                    String::from_utf8_lossy(msg)
+                )));
+            }
+            "sys_output" => {
+                let [_output_id, _output_value] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                // The output registers are only observable by the prover.
+            }
+
+            // Input and output
+            "sys_read" => {
+                let [fd, buf, nrequested] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let fd = this.read_scalar(fd)?.to_u32()?;
+                let buf = this.read_pointer(buf)?;
+                let nrequested = this.read_target_usize(nrequested)?;
+                if fd != STDIN {
+                    throw_unsup_format!("`sys_read` is only supported on stdin, not on fd {fd}");
+                }
+                let bytes = this.machine.zkvm_io.read_stdin(nrequested).to_owned();
+                this.write_bytes_ptr(buf, bytes.iter().copied())?;
+                this.write_scalar(
+                    Scalar::from_target_usize(u64::try_from(bytes.len()).unwrap(), this),
+                    dest,
+                )?;
+            }
+            "sys_write" => {
+                let [fd, buf, nbytes] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let fd = this.read_scalar(fd)?.to_u32()?;
+                let buf = this.read_pointer(buf)?;
+                let nbytes = this.read_target_usize(nbytes)?;
+                let bytes = this.read_bytes_ptr_strip_provenance(buf, Size::from_bytes(nbytes))?;
+                match fd {
+                    STDOUT | STDERR if this.machine.mute_stdout_stderr => {}
+                    // Like the host, we ignore failures to write to stdout and stderr.
+                    STDOUT => {
+                        io::stdout().write_all(bytes).ok();
+                    }
+                    STDERR => {
+                        io::stderr().write_all(bytes).ok();
+                    }
+                    JOURNAL => {
+                        let bytes = bytes.to_owned();
+                        if let Err(err) = this.machine.zkvm_io.write_journal(&bytes) {
+                            throw_unsup_format!("failed to write the journal: {err}");
+                        }
+                    }
+                    _ => throw_unsup_format!("`sys_write` is not supported on fd {fd}"),
+                }
+            }
+            "sys_log" => {
+                let [msg, len] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let msg = this.read_pointer(msg)?;
+                let len = this.read_target_usize(len)?;
+                let msg = this.read_bytes_ptr_strip_provenance(msg, Size::from_bytes(len))?;
+                if !this.machine.mute_stdout_stderr {
+                    // The host prints each log message on its own line.
+                    let mut stderr = io::stderr().lock();
+                    stderr.write_all(msg).and_then(|()| stderr.write_all(b"\n")).ok();
+                }
+            }
+
+            // Environment
+            "sys_getenv" => {
+                let [recv_buf, words, varname, varname_len] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let result = this.sys_getenv(recv_buf, words, varname, varname_len)?;
+                this.write_scalar(result, dest)?;
+            }
+            "sys_argc" => {
+                let [] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let argc = this.machine.argc.expect("machine must be initialized");
+                let argc = this.ptr_to_mplace(argc, this.machine.layouts.usize);
+                let argc = this.read_target_usize(&argc)?;
+                this.write_scalar(Scalar::from_target_usize(argc, this), dest)?;
+            }
+            "sys_argv" => {
+                let [out_words, out_nwords, arg_index] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let out_words = this.read_pointer(out_words)?;
+                let out_nwords = this.read_target_usize(out_nwords)?;
+                let arg_index = this.read_target_usize(arg_index)?;
+
+                let argc = this.machine.argc.expect("machine must be initialized");
+                let argc = this.ptr_to_mplace(argc, this.machine.layouts.usize);
+                let argc = this.read_target_usize(&argc)?;
+                if arg_index >= argc {
+                    throw_ub_format!(
+                        "`sys_argv` index {arg_index} is out of bounds for {argc} arguments"
+                    );
+                }
+                // `machine.argv` points to the pointer to the array of C strings.
+                let ptr_layout = this.machine.layouts.const_raw_ptr;
+                let argv = this.machine.argv.expect("machine must be initialized");
+                let argv = this.read_pointer(&this.ptr_to_mplace(argv, ptr_layout))?;
+                let offset = this.pointer_size().checked_mul(arg_index, this).unwrap();
+                let arg = argv.offset(offset, this)?;
+                let arg = this.read_pointer(&this.ptr_to_mplace(arg, ptr_layout))?;
+                let arg = this.read_c_str(arg)?.to_owned();
+
+                // Like `sys_getenv`, this copies as much as fits, and returns the full length. std
+                // first calls it with a null buffer to learn that length.
+                let capacity = out_nwords.checked_mul(WORD_SIZE).unwrap();
+                let copied = arg.len().min(usize::try_from(capacity).unwrap_or(usize::MAX));
+                if copied > 0 {
+                    this.write_bytes_ptr(out_words, arg[..copied].iter().copied())?;
+                }
+                this.write_scalar(
+                    Scalar::from_target_usize(u64::try_from(arg.len()).unwrap(), this),
+                    dest,
+                )?;
+            }
+
+            // Allocation
+            "sys_alloc_words" => {
+                let [nwords] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let nwords = this.read_target_usize(nwords)?;
+                let size = nwords.checked_mul(WORD_SIZE).unwrap();
+                let ptr = this.allocate_ptr(
+                    Size::from_bytes(size),
+                    Align::from_bytes(WORD_SIZE).unwrap(),
+                    MiriMemoryKind::ZkvmHeap.into(),
+                )?;
+                this.write_pointer(ptr, dest)?;
+            }
+            "sys_alloc_aligned" => {
+                // Unlike `sys_alloc_words`, this takes its size in bytes.
+                let [size, align] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let size = this.read_target_usize(size)?;
+                let align = this.read_target_usize(align)?;
+                if !align.is_power_of_two() {
+                    throw_ub_format!(
+                        "`sys_alloc_aligned` called with non-power-of-2 alignment {align}"
+                    );
+                }
+                let ptr = this.allocate_ptr(
+                    Size::from_bytes(size),
+                    Align::from_bytes(align).unwrap(),
+                    MiriMemoryKind::ZkvmHeap.into(),
+                )?;
+                this.write_pointer(ptr, dest)?;
+            }
+
+            // Randomness
+            "sys_rand" => {
+                // The size of the buffer is in bytes.
+                let [recv_buf, nbytes] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let recv_buf = this.read_pointer(recv_buf)?;
+                let nbytes = this.read_target_usize(nbytes)?;
+                this.gen_random(recv_buf, nbytes)?;
+            }
+
+            // Accelerators
+            "sys_sha_compress" => {
+                let [out_state, in_state, block1_ptr, block2_ptr] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let out_state = this.read_pointer(out_state)?;
+                let in_state = this.read_pointer(in_state)?;
+                let block1_ptr = this.read_pointer(block1_ptr)?;
+                let block2_ptr = this.read_pointer(block2_ptr)?;
+                this.sha256_compress(out_state, in_state, &[[block1_ptr, block2_ptr]])?;
+            }
+            "sys_sha_buffer" => {
+                let [out_state, in_state, buf, count] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let out_state = this.read_pointer(out_state)?;
+                let in_state = this.read_pointer(in_state)?;
+                let buf = this.read_pointer(buf)?;
+                let count = this.read_scalar(count)?.to_u32()?;
+                let blocks = this.sha256_blocks(buf, count.into())?;
+                this.sha256_compress(out_state, in_state, &blocks)?;
+                this.charge_cost("call.sys_sha_buffer.per-block", count.into());
+            }
+
+            // Miscellaneous
+            "sys_cycle_count" => {
+                let [] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                // With `-Zmiri-cost-model`, report the estimated cost so far. Otherwise, the number
+                // of basic blocks executed so far at least grows as the program runs. Like the real
+                // counter, this wraps around.
+                let count = match &this.machine.cost_model {
+                    Some(cost_model) => cost_model.total(),
+                    None => this.machine.basic_block_count,
+                };
+                let count = count & this.target_usize_max();
+                this.write_scalar(Scalar::from_target_usize(count, this), dest)?;
+            }
+
+            _ => return Ok(EmulateForeignItemResult::NotSupported),
+        }
+
+        Ok(EmulateForeignItemResult::NeedsJumping)
+    }
+}
@@ -0,0 +1,56 @@
+pub mod foreign_items;
+
+mod sha256;
+
+use std::fs::File;
+use std::io::{self, Write};
+
+use rustc_middle::ty::TyCtxt;
+
+use crate::*;
+
+/// The size of a word on the zkVM. Several syscalls measure their buffers in words.
+pub(crate) const WORD_SIZE: u64 = 4;
+
+/// The host side of the zkVM's input and output streams.
+pub struct ZkvmIo {
+    /// The bytes the guest can read from stdin.
+    stdin: Vec<u8>,
+    /// How many of the `stdin` bytes the guest has read so far.
+    stdin_pos: usize,
+    /// Where the journal goes. Without `-Zmiri-zkvm-journal`, it is discarded.
+    journal: Option<File>,
+}
+
+impl ZkvmIo {
+    pub fn new(tcx: TyCtxt<'_>, config: &MiriConfig) -> Self {
+        let stdin = match &config.zkvm_stdin {
+            Some(path) =>
+                std::fs::read(path).unwrap_or_else(|err| {
+                    tcx.sess.fatal(format!("failed to read `{}`: {err}", path.display()))
+                }),
+            None => Vec::new(),
+        };
+        let journal = config.zkvm_journal.as_ref().map(|path| {
+            File::create(path).unwrap_or_else(|err| {
+                tcx.sess.fatal(format!("failed to create `{}`: {err}", path.display()))
+            })
+        });
+        ZkvmIo { stdin, stdin_pos: 0, journal }
+    }
+
+    /// Reads at most `len` bytes from stdin. Returns an empty slice once all input is consumed.
+    fn read_stdin(&mut self, len: u64) -> &[u8] {
+        let remaining = &self.stdin[self.stdin_pos..];
+        let len = usize::try_from(len).unwrap_or(usize::MAX).min(remaining.len());
+        self.stdin_pos = self.stdin_pos.checked_add(len).unwrap();
+        &remaining[..len]
+    }
+
+    fn write_journal(&mut self, bytes: &[u8]) -> io::Result<()> {
+        match &mut self.journal {
+            Some(journal) => journal.write_all(bytes),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use rustc_target::abi::Size;
+
+use crate::*;
+
+/// The number of words in a SHA-256 state.
+const DIGEST_WORDS: usize = 8;
+/// The number of bytes in half a SHA-256 block, which is also the size of a state.
+const HALF_BLOCK_BYTES: u64 = 32;
+/// The number of bytes in a SHA-256 block.
+const BLOCK_BYTES: u64 = 2 * HALF_BLOCK_BYTES;
+
+#[rustfmt::skip]
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// The SHA-256 compression function, as specified in FIPS 180-4, section 6.2.2.
+#[allow(clippy::arithmetic_side_effects)] // indices are in bounds, and the sums wrap explicitly
+fn compress(state: &mut [u32; DIGEST_WORDS], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (w, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *w = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (k, w) in K.iter().zip(w) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(w);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s = s.wrapping_add(v);
+    }
+}
+
+impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+pub trait EvalContextExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    /// Emulates the zkVM's SHA-256 accelerator: compresses `blocks` into the state at `in_state`,
+    /// in order, and stores the result at `out_state`. Each block is given as pointers to its two
+    /// halves.
+    ///
+    /// The states are laid out like a digest, i.e. with each word in big-endian byte order.
+    fn sha256_compress(
+        &mut self,
+        out_state: Pointer<Option<Provenance>>,
+        in_state: Pointer<Option<Provenance>>,
+        blocks: &[[Pointer<Option<Provenance>>; 2]],
+    ) -> InterpResult<'tcx> {
+        let this = self.eval_context_mut();
+        let half_block_size = Size::from_bytes(HALF_BLOCK_BYTES);
+
+        let state_bytes = this.read_bytes_ptr_strip_provenance(in_state, half_block_size)?;
+        let mut state = [0u32; DIGEST_WORDS];
+        for (word, bytes) in state.iter_mut().zip(state_bytes.chunks_exact(4)) {
+            *word = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+
+        for halves in blocks {
+            let mut block = Vec::with_capacity(usize::try_from(BLOCK_BYTES).unwrap());
+            for &half in halves {
+                let half = this.read_bytes_ptr_strip_provenance(half, half_block_size)?;
+                block.extend_from_slice(half);
+            }
+            compress(&mut state, &block);
+        }
+
+        this.write_bytes_ptr(out_state, state.iter().flat_map(|word| word.to_be_bytes()))
+    }
+
+    /// Splits the `count` consecutive blocks starting at `buf` into halves, as expected by
+    /// `sha256_compress`.
+    fn sha256_blocks(
+        &self,
+        buf: Pointer<Option<Provenance>>,
+        count: u64,
+    ) -> InterpResult<'tcx, Vec<[Pointer<Option<Provenance>>; 2]>> {
+        let this = self.eval_context_ref();
+        let half_block_size = Size::from_bytes(HALF_BLOCK_BYTES);
+        let mut blocks = Vec::with_capacity(usize::try_from(count).unwrap());
+        for i in 0..count {
+            let first = buf.offset(Size::from_bytes(i.checked_mul(BLOCK_BYTES).unwrap()), this)?;
+            blocks.push([first, first.offset(half_block_size, this)?]);
+        }
+        Ok(blocks)
+    }
+}
@@ -262,6 +262,41 @@ fn main() -> Result<()> {
         )?;
     }
 
+    if target.contains("zkvm") {
+        cost_model(&target)?;
+    }
+
+    Ok(())
+}
+
+/// Runs `tests/cost-model/cycle_count.rs`, which checks what `sys_cycle_count` reports, and then
+/// checks the profile it leaves behind. This does not fit the ui tests, which cannot look at files
+/// written by Miri.
+fn cost_model(target: &str) -> Result<()> {
+    let msg = format!("## Running the cost model test against miri for {target}");
+    eprintln!("{}", msg.green().bold());
+
+    let config = test_config(target, "", Mode::Pass, /* with dependencies */ false);
+    let profile = config.out_dir.join("cost-model").join("cycle_count.folded");
+    std::fs::create_dir_all(profile.parent().unwrap())?;
+    let _ = std::fs::remove_file(&profile);
+
+    let mut cmd = config.program.build(&config.out_dir);
+    cmd.arg("tests/cost-model/cycle_count.rs");
+    cmd.arg("-Zmiri-cost-model=tests/cost-model/costs.txt");
+    let mut flag = OsString::from("-Zmiri-cost-profile=");
+    flag.push(&profile);
+    cmd.arg(flag);
+    if !cmd.spawn()?.wait()?.success() {
+        panic!("`tests/cost-model/cycle_count.rs` failed");
+    }
+
+    // `mul` is called from `main` and does a single multiplication.
+    let profile = std::fs::read_to_string(&profile)?;
+    assert!(
+        profile.lines().any(|line| line.contains("main;") && line.ends_with("mul 3")),
+        "`mul` was not charged for its multiplication:\n{profile}"
+    );
     Ok(())
 }
 
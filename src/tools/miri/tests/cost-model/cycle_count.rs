@@ -0,0 +1,30 @@
+//! Run by `tests/compiletest.rs` with `-Zmiri-cost-model=tests/cost-model/costs.txt`, which then
+//! checks the profile that was written.
+use std::hint::black_box;
+
+extern "C" {
+    fn sys_cycle_count() -> usize;
+    fn sys_rand(recv_buf: *mut u8, nbytes: usize);
+}
+
+fn cycle_count() -> usize {
+    unsafe { sys_cycle_count() }
+}
+
+#[inline(never)]
+fn mul(a: u32, b: u32) -> u32 {
+    a * b
+}
+
+fn main() {
+    let before = cycle_count();
+    assert_eq!(cycle_count(), before);
+
+    assert_eq!(mul(black_box(6), black_box(7)), 42);
+    let after_mul = cycle_count();
+    assert_eq!(after_mul - before, 3);
+
+    let mut buf = [0u8; 16];
+    unsafe { sys_rand(buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(cycle_count() - after_mul, 100);
+}
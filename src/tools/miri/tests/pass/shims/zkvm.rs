@@ -0,0 +1,69 @@
+//@only-target-zkvm
+#![feature(hash_sha256, zkvm_std)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::env;
+use std::hash::Sha256;
+use std::io::Read;
+
+fn test_args() {
+    // `sys_argc` and `sys_argv`; the first argument is the program name.
+    assert!(env::args().count() >= 1);
+}
+
+fn test_env() {
+    // `sys_getenv`; nothing is forwarded from the host under isolation.
+    assert!(env::var_os("HOME").is_none());
+}
+
+fn test_stdin() {
+    // `sys_read`; without `-Zmiri-zkvm-stdin`, stdin is empty.
+    let mut buf = Vec::new();
+    assert_eq!(std::io::stdin().read_to_end(&mut buf).unwrap(), 0);
+}
+
+fn test_alloc() {
+    // `sys_alloc_aligned`, which never deallocates.
+    let layout = Layout::from_size_align(64, 32).unwrap();
+    unsafe {
+        let ptr = System.alloc(layout);
+        assert_eq!(ptr.align_offset(32), 0);
+        ptr.write_bytes(0xa5, 64);
+        assert_eq!(*ptr.add(63), 0xa5);
+        System.dealloc(ptr, layout);
+    }
+}
+
+fn test_rand() {
+    // `sys_rand`, through the keys of `RandomState`.
+    let mut map = HashMap::new();
+    map.insert(1, 2);
+    assert_eq!(map[&1], 2);
+}
+
+fn test_sha256() {
+    // `sys_sha_buffer` for aligned input, `sys_sha_compress` otherwise.
+    let expected = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+        0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+        0x15, 0xad,
+    ];
+    assert_eq!(Sha256::digest(b"abc"), expected);
+
+    let data = [b'a'; 200];
+    assert_eq!(Sha256::digest(&data[..199]), Sha256::digest(&data[1..]));
+}
+
+fn main() {
+    test_args();
+    test_env();
+    test_stdin();
+    test_alloc();
+    test_rand();
+    test_sha256();
+
+    // `sys_write` and `sys_log`.
+    println!("hello from the guest");
+    std::os::zkvm::log("logged by the guest");
+}
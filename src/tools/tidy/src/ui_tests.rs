@@ -14,12 +14,13 @@
 const ROOT_ENTRY_LIMIT: usize = 867;
 
 const EXPECTED_TEST_FILE_EXTENSIONS: &[&str] = &[
-    "rs",     // test source files
-    "stderr", // expected stderr file, corresponds to a rs file
-    "stdout", // expected stdout file, corresponds to a rs file
-    "fixed",  // expected source file after applying fixes
-    "md",     // test directory descriptions
-    "ftl",    // translation tests
+    "rs",      // test source files
+    "stderr",  // expected stderr file, corresponds to a rs file
+    "stdout",  // expected stdout file, corresponds to a rs file
+    "fixed",   // expected source file after applying fixes
+    "journal", // expected journal committed by a zkVM test, corresponds to a rs file
+    "md",      // test directory descriptions
+    "ftl",     // translation tests
 ];
 
 const EXTENSION_EXCEPTION_PATHS: &[&str] = &[
@@ -36,6 +37,7 @@
     "tests/ui/unused-crate-deps/test.mk", // why would you use make
     "tests/ui/proc-macro/auxiliary/included-file.txt", // more include
     "tests/ui/invalid/foo.natvis.xml", // sample debugger visualizer
+    "tests/ui/layout/layout-snapshot-changed.txt", // layout snapshot to check against
 ];
 
 fn check_entries(tests_path: &Path, bad: &mut bool) {
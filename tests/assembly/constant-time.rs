@@ -0,0 +1,211 @@
+// Check that masks passed through `value_barrier`, the way `core::ct` does, are
+// not turned back into branches or conditional moves.
+
+// revisions: x86_64 riscv32
+// assembly-output: emit-asm
+// compile-flags: -O -C overflow-checks=off -Z merge-functions=disabled
+//[x86_64] compile-flags: --target x86_64-unknown-linux-gnu
+//[x86_64] needs-llvm-components: x86
+//[riscv32] compile-flags: --target riscv32imac-unknown-none-elf
+//[riscv32] needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, intrinsics)]
+#![crate_type = "rlib"]
+#![no_core]
+#![allow(dead_code)]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+
+#[lang = "eq"]
+trait PartialEq<Rhs: ?Sized = Self> {
+    fn eq(&self, other: &Rhs) -> bool;
+    fn ne(&self, other: &Rhs) -> bool;
+}
+#[lang = "sub"]
+trait Sub<Rhs = Self> {
+    type Output;
+    fn sub(self, rhs: Rhs) -> Self::Output;
+}
+#[lang = "bitand"]
+trait BitAnd<Rhs = Self> {
+    type Output;
+    fn bitand(self, rhs: Rhs) -> Self::Output;
+}
+#[lang = "bitor"]
+trait BitOr<Rhs = Self> {
+    type Output;
+    fn bitor(self, rhs: Rhs) -> Self::Output;
+}
+#[lang = "bitxor"]
+trait BitXor<Rhs = Self> {
+    type Output;
+    fn bitxor(self, rhs: Rhs) -> Self::Output;
+}
+#[lang = "not"]
+trait Not {
+    type Output;
+    fn not(self) -> Self::Output;
+}
+#[lang = "shr"]
+trait Shr<Rhs = Self> {
+    type Output;
+    fn shr(self, rhs: Rhs) -> Self::Output;
+}
+
+macro_rules! impl_ops {
+    ($($t:ty)*) => {$(
+        impl Copy for $t {}
+        impl PartialEq for $t {
+            fn eq(&self, other: &$t) -> bool { *self == *other }
+            fn ne(&self, other: &$t) -> bool { *self != *other }
+        }
+        impl Sub for $t {
+            type Output = $t;
+            fn sub(self, rhs: $t) -> $t { self - rhs }
+        }
+        impl BitAnd for $t {
+            type Output = $t;
+            fn bitand(self, rhs: $t) -> $t { self & rhs }
+        }
+        impl BitOr for $t {
+            type Output = $t;
+            fn bitor(self, rhs: $t) -> $t { self | rhs }
+        }
+        impl BitXor for $t {
+            type Output = $t;
+            fn bitxor(self, rhs: $t) -> $t { self ^ rhs }
+        }
+        impl Not for $t {
+            type Output = $t;
+            fn not(self) -> $t { !self }
+        }
+        impl Shr<u32> for $t {
+            type Output = $t;
+            fn shr(self, rhs: u32) -> $t { self >> rhs }
+        }
+    )*};
+}
+
+impl_ops!(u8 u32 u64);
+impl Copy for bool {}
+
+extern "rust-intrinsic" {
+    fn value_barrier<T: Copy>(value: T) -> T;
+}
+
+// These mirror `ct_eq`, `ct_lt` and `ct_select` for unsigned integers in
+// library/core/src/ct.rs, and must be kept in sync with it.
+macro_rules! ct_ops {
+    ($eq:ident, $lt:ident, $select:ident, $t:ty, $bits:literal) => {
+        #[inline(always)]
+        fn $eq(x: $t, y: $t) -> bool {
+            let diff = x ^ y;
+            let nonzero = (diff | (0 - diff)) >> ($bits - 1);
+            unsafe { value_barrier(nonzero ^ 1) != 0 }
+        }
+
+        #[inline(always)]
+        fn $lt(x: $t, y: $t) -> bool {
+            let borrow = (!x & y) | (!(x ^ y) & (x - y));
+            unsafe { value_barrier(borrow >> ($bits - 1)) != 0 }
+        }
+
+        #[inline(always)]
+        fn $select(condition: bool, a: $t, b: $t) -> $t {
+            let mask = unsafe { value_barrier(0 - condition as $t) };
+            b ^ (mask & (a ^ b))
+        }
+    };
+}
+
+ct_ops!(ct_eq_u8, ct_lt_u8, ct_select_u8, u8, 8);
+ct_ops!(ct_eq_u32, ct_lt_u32, ct_select_u32, u32, 32);
+ct_ops!(ct_eq_u64, ct_lt_u64, ct_select_u64, u64, 64);
+
+// CHECK-LABEL: select_u32:
+#[no_mangle]
+pub fn select_u32(condition: bool, a: u32, b: u32) -> u32 {
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: #APP
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: ret
+    ct_select_u32(condition, a, b)
+}
+
+// CHECK-LABEL: select_eq_u64:
+#[no_mangle]
+pub fn select_eq_u64(x: u64, y: u64, a: u64, b: u64) -> u64 {
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: #APP
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: ret
+    ct_select_u64(ct_eq_u64(x, y), a, b)
+}
+
+// CHECK-LABEL: select_lt_u32:
+#[no_mangle]
+pub fn select_lt_u32(x: u32, y: u32, a: u32, b: u32) -> u32 {
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: #APP
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: ret
+    ct_select_u32(ct_lt_u32(x, y), a, b)
+}
+
+// Like `ct_eq_bytes`, for arrays, so that there is no length check.
+// CHECK-LABEL: eq_bytes:
+#[no_mangle]
+pub fn eq_bytes(a: &[u8; 4], b: &[u8; 4]) -> bool {
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: #APP
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: ret
+    let [a0, a1, a2, a3] = *a;
+    let [b0, b1, b2, b3] = *b;
+    let mut diff = 0;
+    diff = unsafe { value_barrier(diff | (a0 ^ b0)) };
+    diff = unsafe { value_barrier(diff | (a1 ^ b1)) };
+    diff = unsafe { value_barrier(diff | (a2 ^ b2)) };
+    diff = unsafe { value_barrier(diff | (a3 ^ b3)) };
+    ct_eq_u8(diff, 0)
+}
+
+// CHECK-LABEL: select_bytes:
+#[no_mangle]
+pub fn select_bytes(condition: bool, a: &[u8; 4], b: &[u8; 4], dest: &mut [u8; 4]) {
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: #APP
+    // x86_64-NOT: {{\sj[a-z]+\s}}
+    // x86_64-NOT: cmov
+    // riscv32-NOT: {{\sb[a-z]+\s}}
+    // CHECK: ret
+    let [a0, a1, a2, a3] = *a;
+    let [b0, b1, b2, b3] = *b;
+    *dest = [
+        ct_select_u8(condition, a0, b0),
+        ct_select_u8(condition, a1, b1),
+        ct_select_u8(condition, a2, b2),
+        ct_select_u8(condition, a3, b3),
+    ];
+}
@@ -0,0 +1,80 @@
+// Check that the limb products of `core::num::bigint` lower to plain
+// `mul`/`mulhu` pairs with the multiply extension, without libcalls.
+//
+// `core` is not available for cross targets here, so this checks copies of the
+// limb arithmetic, which must be kept in sync with `mul_add` in
+// library/core/src/num/bigint.rs and `carrying_mul` in library/core/src/num/mod.rs.
+
+// revisions: riscv32im riscv32i
+// assembly-output: emit-asm
+// compile-flags: -O -C overflow-checks=off
+//[riscv32im] compile-flags: --target riscv32im-unknown-none-elf
+//[riscv32im] needs-llvm-components: riscv
+//[riscv32i] compile-flags: --target riscv32i-unknown-none-elf
+//[riscv32i] needs-llvm-components: riscv
+
+#![feature(no_core, lang_items, intrinsics)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "shr"]
+trait Shr<Rhs = Self> {
+    type Output;
+    fn shr(self, rhs: Rhs) -> Self::Output;
+}
+
+impl Copy for u32 {}
+impl Copy for u64 {}
+impl Copy for bool {}
+impl Shr<u32> for u64 {
+    type Output = u64;
+    fn shr(self, rhs: u32) -> u64 {
+        self >> rhs
+    }
+}
+
+extern "rust-intrinsic" {
+    fn unchecked_mul<T: Copy>(x: T, y: T) -> T;
+    fn unchecked_add<T: Copy>(x: T, y: T) -> T;
+    fn add_with_overflow<T: Copy>(x: T, y: T) -> (T, bool);
+}
+
+// `u32::carrying_mul`.
+#[inline(always)]
+fn carrying_mul(a: u32, b: u32, carry: u32) -> (u32, u32) {
+    let wide = unsafe { unchecked_add(unchecked_mul(a as u64, b as u64), carry as u64) };
+    (wide as u32, (wide >> 32) as u32)
+}
+
+// `mul_add` in `core::num::bigint`.
+#[inline(always)]
+fn mul_add(a: u32, b: u32, acc: u32, carry: u32) -> (u32, u32) {
+    let (lo, hi) = carrying_mul(a, b, carry);
+    let (lo, c) = unsafe { add_with_overflow(lo, acc) };
+    (lo, unsafe { unchecked_add(hi, c as u32) })
+}
+
+// `Uint::<2>::widening_mul`, unrolled.
+// CHECK-LABEL: widening_mul_2:
+#[no_mangle]
+pub fn widening_mul_2(a: [u32; 2], b: [u32; 2]) -> [u32; 4] {
+    // riscv32im-NOT: call
+    // riscv32im-DAG: mulhu
+    // riscv32im-DAG: {{\smul\s}}
+    // riscv32im-NOT: call
+    // Without the multiply extension, the products are libcalls.
+    // riscv32i: call __muldi3
+    // CHECK: ret
+    let [a0, a1] = a;
+    let [b0, b1] = b;
+    let (r0, carry) = mul_add(a0, b0, 0, 0);
+    let (r1, carry) = mul_add(a0, b1, 0, carry);
+    let r2 = carry;
+    let (r1, carry) = mul_add(a1, b0, r1, 0);
+    let (r2, carry) = mul_add(a1, b1, r2, carry);
+    [r0, r1, r2, carry]
+}
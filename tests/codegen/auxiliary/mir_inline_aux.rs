@@ -0,0 +1,17 @@
+#![crate_type = "lib"]
+#![feature(mir_inline_attribute)]
+
+#[mir_inline(always)]
+pub fn always(x: u32) -> u32 {
+    x ^ 0x5a5a
+}
+
+#[mir_inline(cost = 0)]
+pub fn free(x: u32) -> u32 {
+    x ^ 0xa5a5
+}
+
+#[inline]
+pub fn plain(x: u32) -> u32 {
+    x ^ 0xffff
+}
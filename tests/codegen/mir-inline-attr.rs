@@ -0,0 +1,37 @@
+// Checks that `#[mir_inline]` on a function in another crate makes the MIR inliner inline it,
+// even when the inlining thresholds don't allow any other function to be inlined.
+//
+// aux-build:mir_inline_aux.rs
+// compile-flags: -Copt-level=0 -Zinline-mir=yes -Zinline-mir-threshold=0
+// compile-flags: -Zinline-mir-hint-threshold=0
+
+#![crate_type = "lib"]
+
+extern crate mir_inline_aux;
+
+// CHECK-LABEL: @call_always(
+#[no_mangle]
+pub fn call_always(x: u32) -> u32 {
+    // CHECK-NOT: call
+    // CHECK: xor i32 {{.*}}, 23130
+    // CHECK-NOT: call
+    // CHECK: ret i32
+    mir_inline_aux::always(x)
+}
+
+// CHECK-LABEL: @call_free(
+#[no_mangle]
+pub fn call_free(x: u32) -> u32 {
+    // CHECK-NOT: call
+    // CHECK: xor i32 {{.*}}, 42405
+    // CHECK-NOT: call
+    // CHECK: ret i32
+    mir_inline_aux::free(x)
+}
+
+// CHECK-LABEL: @call_plain(
+#[no_mangle]
+pub fn call_plain(x: u32) -> u32 {
+    // CHECK: call {{.*}}plain
+    mir_inline_aux::plain(x)
+}
@@ -0,0 +1,9 @@
+#[inline(never)]
+fn location() -> &'static std::panic::Location<'static> {
+    std::panic::Location::caller()
+}
+
+fn main() {
+    println!("{}", file!());
+    println!("{}", location());
+}
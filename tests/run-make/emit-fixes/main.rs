@@ -0,0 +1,6 @@
+fn main() {
+    let x = (1 + 2);
+    if (x > 2) {
+        println!("{x}");
+    }
+}
@@ -0,0 +1,25 @@
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+pub enum Message {
+    Byte(u8),
+    Word(u32),
+}
+
+#[repr(C)]
+pub struct Tuple(pub u8, pub u16);
+
+mod private {
+    // Public, and reexported below.
+    pub struct Reexported;
+
+    // Public, but not reachable from outside the crate.
+    pub struct Unreachable(pub u8);
+}
+
+pub use private::Reexported;
+
+// Generic types do not have a layout on their own.
+pub struct Generic<T>(pub T);
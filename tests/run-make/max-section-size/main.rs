@@ -0,0 +1,7 @@
+static mut BIG_TABLE: [u8; 8192] = [1; 8192];
+
+fn main() {
+    let table = unsafe { std::hint::black_box(&mut BIG_TABLE) };
+    table[0] += 1;
+    println!("{}", table[0]);
+}
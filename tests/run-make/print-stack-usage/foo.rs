@@ -0,0 +1,27 @@
+#[inline(never)]
+fn leaf(buf: &mut [u64; 64]) -> u64 {
+    buf.iter_mut().fold(0, |acc, x| {
+        *x = acc;
+        acc ^ *x
+    })
+}
+
+#[inline(never)]
+fn middle() -> u64 {
+    let mut buf = std::hint::black_box([0; 64]);
+    leaf(&mut buf)
+}
+
+fn main() {
+    std::hint::black_box(middle());
+}
+
+#[no_mangle]
+pub extern "C" fn recursive(n: u32) -> u32 {
+    if n == 0 { 0 } else { recursive(n - 1) + 1 }
+}
+
+#[no_mangle]
+pub extern "C" fn indirect(f: fn() -> u64) -> u64 {
+    f()
+}
@@ -0,0 +1,28 @@
+#![crate_type = "rlib"]
+
+pub struct Counter(u64);
+
+impl Counter {
+    #[inline(never)]
+    pub fn bump(&mut self) -> u64 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+impl Clone for Counter {
+    #[inline(never)]
+    fn clone(&self) -> Counter {
+        Counter(self.0)
+    }
+}
+
+#[inline(never)]
+pub fn used() -> Counter {
+    Counter(std::env::args().count() as u64)
+}
+
+#[inline(never)]
+pub fn unused() -> u64 {
+    std::env::args().count() as u64 * 2
+}
@@ -0,0 +1,6 @@
+extern crate dep;
+
+fn main() {
+    let mut counter = dep::used();
+    println!("{}", counter.bump());
+}
@@ -0,0 +1,37 @@
+#![feature(mir_inline_attribute)]
+#![crate_type = "lib"]
+
+#[mir_inline(always)]
+pub fn always() {}
+
+#[mir_inline(cost = 10)]
+pub fn cost() {}
+
+#[mir_inline(never)] //~ ERROR malformed `mir_inline` attribute input
+pub fn never() {}
+
+#[mir_inline(cost = "10")] //~ ERROR malformed `mir_inline` attribute input
+pub fn string_cost() {}
+
+#[mir_inline(always, cost = 10)] //~ ERROR malformed `mir_inline` attribute input
+pub fn both() {}
+
+#[mir_inline(always)] //~ ERROR cannot be used on a function that is `#[inline(never)]`
+#[inline(never)]
+pub fn inline_never() {}
+
+#[mir_inline(always)] //~ ERROR attribute should be applied to a function definition
+pub struct S;
+
+impl S {
+    #[mir_inline(cost = 0)]
+    pub fn method(&self) {}
+}
+
+pub trait T {
+    #[mir_inline(always)] //~ ERROR attribute should be applied to a function definition
+    fn required();
+
+    #[mir_inline(cost = 0)]
+    fn provided() {}
+}
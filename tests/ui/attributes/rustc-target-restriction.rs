@@ -0,0 +1,19 @@
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+
+#[rustc_unsupported_on(target_os = "zkvm", reason = "no foo")]
+fn foo() {}
+
+#[rustc_expensive_on(target_os = "zkvm")]
+mod bar {}
+
+#[rustc_unsupported_on(reason = "no target")] //~ ERROR malformed
+fn no_target() {}
+
+#[rustc_expensive_on(target_os = "zkvm", cost = "high")] //~ ERROR malformed
+fn unknown_key() {}
+
+#[rustc_unsupported_on(target_os = "zkvm")] //~ ERROR should be applied to a function
+struct Baz;
+
+fn main() {}
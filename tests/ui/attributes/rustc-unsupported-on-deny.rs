@@ -0,0 +1,40 @@
+// Checks that final artifacts built for a target with `deny_unsupported_apis` reject uses of
+// functions marked with `#[rustc_unsupported_on]` for that target.
+
+// build-fail
+// compile-flags: --target riscv32im-succinct-zkvm-elf
+// needs-llvm-components: riscv
+
+#![crate_type = "staticlib"]
+#![feature(no_core, lang_items, rustc_attrs)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[rustc_unsupported_on(target_os = "zkvm", reason = "there is no clock inside the zkVM")]
+fn now() {}
+
+#[rustc_unsupported_on(target_os = "linux")]
+fn spawn() {}
+
+fn elapsed<T>() {
+    now(); //~ ERROR `now` is not supported on `target_os = "zkvm"`
+}
+
+// Only the use from outside of the module is reported, not the calls within it.
+#[rustc_unsupported_on(target_os = "zkvm")]
+mod fs {
+    pub fn read() {
+        inner();
+    }
+
+    fn inner() {}
+}
+
+#[no_mangle]
+pub extern "C" fn entry() {
+    elapsed::<u8>();
+    spawn();
+    fs::read(); //~ ERROR `fs::read` is not supported on `target_os = "zkvm"`
+}
@@ -0,0 +1,6 @@
+// check-pass
+// compile-flags: --error-format=sarif -Zunstable-options
+
+// A build without diagnostics still writes a log, with no results.
+
+fn main() {}
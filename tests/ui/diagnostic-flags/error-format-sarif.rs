@@ -0,0 +1,6 @@
+// check-pass
+// compile-flags: --error-format=sarif -Zunstable-options
+
+fn main() {
+    let x = 1;
+}
@@ -0,0 +1,7 @@
+#![crate_type = "lib"]
+
+#[mir_inline(always)] //~ ERROR the `#[mir_inline]` attribute is an experimental feature
+pub fn always() {}
+
+#[mir_inline(cost = 10)] //~ ERROR the `#[mir_inline]` attribute is an experimental feature
+pub fn cost() {}
@@ -0,0 +1,21 @@
+// Check that `-Z layout-snapshot-check` reports public types whose layout differs from
+// the snapshot, or that are not in the crate anymore.
+
+// compile-flags: --crate-type=lib -Z layout-snapshot-check
+// compile-flags: -Z layout-snapshot={{src-base}}/layout/layout-snapshot-changed.txt
+
+pub struct Changed { //~ ERROR layout of `Changed` differs from the layout snapshot
+    pub a: u32,
+}
+
+#[repr(C)]
+pub struct Unchanged {
+    pub a: u8,
+    pub b: u32,
+}
+
+// Not public, so not checked.
+#[allow(dead_code)]
+struct Private {
+    a: u8,
+}
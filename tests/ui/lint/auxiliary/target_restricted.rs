@@ -0,0 +1,21 @@
+#![feature(rustc_attrs)]
+
+#[rustc_unsupported_on(target_os = "linux", reason = "there is no foo on Linux")]
+pub fn foo() {}
+
+#[rustc_expensive_on(target_os = "linux")]
+pub fn bar() {}
+
+#[rustc_unsupported_on(target_os = "none")]
+pub fn baz() {}
+
+pub struct Widget;
+
+#[rustc_unsupported_on(target_os = "linux")]
+impl Widget {
+    pub fn new() -> Widget {
+        Widget
+    }
+
+    pub fn frob(&self) {}
+}
@@ -0,0 +1,19 @@
+// Checks that uses of APIs marked with `#[rustc_unsupported_on]` and
+// `#[rustc_expensive_on]` for the current `target_os` are linted.
+
+// aux-build:target_restricted.rs
+// only-linux
+
+#![deny(unsupported_on_target, expensive_on_target)]
+
+extern crate target_restricted;
+
+use target_restricted::{bar, baz, foo, Widget};
+
+fn main() {
+    foo(); //~ ERROR `target_restricted::foo` is not supported on `target_os = "linux"`
+    bar(); //~ ERROR `target_restricted::bar` is very expensive on `target_os = "linux"`
+    baz();
+    let widget = Widget::new(); //~ ERROR `target_restricted::Widget::new` is not supported
+    widget.frob(); //~ ERROR `target_restricted::Widget::frob` is not supported
+}
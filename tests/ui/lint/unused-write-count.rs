@@ -0,0 +1,30 @@
+// check-pass
+// run-rustfix
+
+#![warn(unused_write_count)]
+
+use std::io::{self, Write};
+
+fn discarded(out: &mut impl Write) -> io::Result<()> {
+    out.write(b"a")?;
+    out.write(b"b").unwrap();
+    out.write(b"c").expect("write failed");
+    let _ = out.write(b"d");
+    Write::write(out, b"e")?;
+    Ok(())
+}
+
+fn checked(out: &mut impl Write) -> io::Result<usize> {
+    // The count is used, or there is no count.
+    let n = out.write(b"a")?;
+    assert_eq!(out.write(b"b")?, 1);
+    out.write_all(b"c")?;
+    out.flush()?;
+    Ok(n + out.write(b"d")?)
+}
+
+fn main() {
+    let mut out = Vec::new();
+    discarded(&mut out).unwrap();
+    checked(&mut out).unwrap();
+}
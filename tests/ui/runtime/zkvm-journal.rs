@@ -0,0 +1,17 @@
+// run-pass
+// check-run-results
+// only-zkvm
+
+// Check that what a guest commits ends up in its journal, separately from its stdout.
+
+extern "C" {
+    fn sys_write(fd: u32, write_buf: *const u8, nbytes: usize);
+}
+
+const JOURNAL: u32 = 3;
+
+fn main() {
+    let committed = b"committed to the journal\n";
+    unsafe { sys_write(JOURNAL, committed.as_ptr(), committed.len()) };
+    println!("printed to stdout");
+}